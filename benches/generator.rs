@@ -0,0 +1,89 @@
+use std::{hint::black_box, path::PathBuf};
+
+use criterion::{BatchSize, BenchmarkId, Criterion, criterion_group, criterion_main};
+use genetica::population::sort_population_descending;
+use sengen::{
+    CONFIG_PATH, LEXICON_DIR,
+    chromosome::{Chromosome, random_population, select_word},
+    ga::next_generation,
+    request::Request,
+    rng,
+    words::{
+        ADJECTIVES, ADVERBS, CONJUNCTIONS, DETERMINERS, INTERJECTIONS, Lexicon, NOUNS, NUMERALS,
+        PREPOSITIONS, PRONOUNS, VERBS,
+    },
+};
+
+const SEED: u64 = 42;
+const POPULATION_SIZES: [i32; 3] = [50, 250, 1000];
+
+fn setup() {
+    CONFIG_PATH.get_or_init(|| PathBuf::from("benches/config.toml"));
+    LEXICON_DIR.get_or_init(|| PathBuf::from("benches/words"));
+    rng::seed(SEED);
+}
+
+fn bench_select_word(c: &mut Criterion) {
+    setup();
+    let request = Request::default();
+    c.bench_function("select_word", |b| b.iter(|| select_word(&request)));
+}
+
+fn bench_collection_select(c: &mut Criterion) {
+    setup();
+    let mut group = c.benchmark_group("collection_select");
+    group.bench_function("nouns", |b| b.iter(|| NOUNS.select()));
+    group.bench_function("pronouns", |b| b.iter(|| PRONOUNS.select()));
+    group.bench_function("verbs", |b| b.iter(|| VERBS.select()));
+    group.bench_function("adverbs", |b| b.iter(|| ADVERBS.select()));
+    group.bench_function("adjectives", |b| b.iter(|| ADJECTIVES.select()));
+    group.bench_function("prepositions", |b| b.iter(|| PREPOSITIONS.select()));
+    group.bench_function("determiners", |b| b.iter(|| DETERMINERS.select()));
+    group.bench_function("conjunctions", |b| b.iter(|| CONJUNCTIONS.select()));
+    group.bench_function("interjections", |b| b.iter(|| INTERJECTIONS.select()));
+    group.bench_function("numerals", |b| b.iter(|| NUMERALS.select()));
+    group.finish();
+}
+
+fn bench_calculate_fitness(c: &mut Criterion) {
+    setup();
+    let request = Request::default();
+    let chromosome = Chromosome::random(&request);
+    c.bench_function("calculate_fitness", |b| {
+        b.iter_batched(
+            || chromosome.clone(),
+            |mut chromosome| {
+                chromosome.evaluate(&request);
+                black_box(chromosome)
+            },
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+fn bench_generation(c: &mut Criterion) {
+    setup();
+    let request = Request::default();
+    let mut group = c.benchmark_group("generation");
+    for population_count in POPULATION_SIZES {
+        rng::seed(SEED);
+        let mut population: Vec<Chromosome> = random_population(&request, population_count);
+        population.iter_mut().for_each(|c| c.evaluate(&request));
+        sort_population_descending(&mut population);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(population_count),
+            &population,
+            |b, population| b.iter(|| next_generation(&request, population, population_count)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_select_word,
+    bench_collection_select,
+    bench_calculate_fitness,
+    bench_generation
+);
+criterion_main!(benches);
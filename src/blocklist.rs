@@ -0,0 +1,129 @@
+use std::process;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::{
+    CONFIG,
+    chromosome::{GeneType, all_genes},
+    render,
+    request::Request,
+};
+
+// How many times a blocked word is replaced before leaving it to the fitness penalty
+const MAX_ATTEMPTS: usize = 100;
+
+/// Words, word pairs and patterns that must never be in a sentence.
+#[derive(Deserialize, Debug, Default)]
+pub struct BlocklistConfig {
+    /// Single words in every form, blocking "dog" also blocks "dogs" and blocking "mice" also
+    /// blocks "mouse".
+    #[serde(default)]
+    pub words: Vec<String>,
+    /// Two words next to each other, separated by a space.
+    #[serde(default)]
+    pub bigrams: Vec<String>,
+    /// Regular expressions matched against the rendered sentence.
+    #[serde(default)]
+    pub patterns: Vec<String>,
+}
+
+struct Blocklist {
+    /// The blocked words and the lemmas they're a form of, in lower case.
+    words: Vec<String>,
+    bigrams: Vec<(String, String)>,
+    patterns: Vec<Regex>,
+}
+
+lazy_static! {
+    static ref BLOCKLIST: Blocklist = compile(&CONFIG.blocklist);
+}
+
+fn compile(config: &BlocklistConfig) -> Blocklist {
+    let bigrams = config
+        .bigrams
+        .iter()
+        .map(|bigram| match bigram.split_once(' ') {
+            Some((first, second)) => (first.to_lowercase(), second.trim().to_lowercase()),
+            None => {
+                eprintln!("Blocked bigram {bigram:?} needs two words separated by a space");
+                process::exit(1);
+            }
+        })
+        .collect();
+    let patterns = config
+        .patterns
+        .iter()
+        .map(|pattern| {
+            Regex::new(pattern).unwrap_or_else(|e| {
+                eprintln!("Error with blocked pattern {pattern:?}: {e}");
+                process::exit(1);
+            })
+        })
+        .collect();
+    let mut words: Vec<String> = config.words.iter().map(|w| w.to_lowercase()).collect();
+    if !words.is_empty() {
+        for gene in all_genes() {
+            let lemma = gene.word().get_word().to_lowercase();
+            if words.contains(&gene.text().to_lowercase()) && !words.contains(&lemma) {
+                words.push(lemma);
+            }
+        }
+    }
+    Blocklist {
+        words,
+        bigrams,
+        patterns,
+    }
+}
+
+/// Whether the word is blocked in any of its forms.
+fn is_blocked_word(gene: GeneType) -> bool {
+    let words = &BLOCKLIST.words;
+    !words.is_empty()
+        && (words.contains(&gene.text().to_lowercase())
+            || words.contains(&gene.word().get_word().to_lowercase()))
+}
+
+/// Selects words until one isn't blocked, giving up after `MAX_ATTEMPTS` so a blocklist
+/// covering every candidate can't hang the search.
+pub fn select_allowed(select: impl Fn() -> GeneType) -> GeneType {
+    let mut gene = select();
+    for _ in 1..MAX_ATTEMPTS {
+        if !is_blocked_word(gene) {
+            break;
+        }
+        gene = select();
+    }
+    gene
+}
+
+/// Whether the sentence matches any word, bigram or pattern on the blocklist.
+pub fn is_blocked(genes: &[GeneType], request: &Request) -> bool {
+    let blocklist = &*BLOCKLIST;
+    if blocklist.words.is_empty() && blocklist.bigrams.is_empty() && blocklist.patterns.is_empty() {
+        return false;
+    }
+    if genes.iter().any(|&gene| is_blocked_word(gene)) {
+        return true;
+    }
+    let texts: Vec<String> = genes.iter().map(|g| g.text().to_lowercase()).collect();
+    let has_bigram = texts.windows(2).any(|pair| {
+        blocklist
+            .bigrams
+            .iter()
+            .any(|(first, second)| *first == pair[0] && *second == pair[1])
+    });
+    if has_bigram {
+        return true;
+    }
+    if blocklist.patterns.is_empty() {
+        return false;
+    }
+    let sentence = render::render(genes, request);
+    blocklist
+        .patterns
+        .iter()
+        .any(|pattern| pattern.is_match(&sentence))
+}
@@ -1,77 +1,168 @@
-use std::sync::Arc;
+use std::borrow::Cow;
 
 use genetica::individual::{DynamicLengthIndividual, Generate, Individual, Mutate};
-use rand::random_range;
+
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    CONFIG, STRUCTURE, WORD_THRESHOLDS, WordType,
-    words::{
-        ADJECTIVES, ADVERBS, CONJUNCTIONS, Collection, DETERMINERS, NOUNS, PREPOSITIONS, VERBS,
-        Word,
-    },
+    AFTER_THRESHOLDS, CONFIG, START_THRESHOLDS, WORD_THRESHOLDS, WordType, blocklist, coherence,
+    grammar,
+    morphology::Form,
+    rates::pick,
+    request::{DEFAULT, Request},
+    required, rng, semantic, tense,
+    words::{Word, collection},
 };
 
-#[derive(Debug, Clone)]
+/// A word stored as its type, index into the lexicon and the form it is inflected in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct GeneType {
-    pub word: Arc<dyn Word>,
-}
-
-fn select_word() -> Arc<dyn Word> {
-    let thresholds = WORD_THRESHOLDS.as_ref();
-    loop {
-        let random_f32 = random_range(0.00..1.00);
-        for &(threshold, word_type) in thresholds {
-            if random_f32 <= threshold {
-                let word = match word_type {
-                    WordType::Noun => NOUNS.select(),
-                    WordType::Verb => VERBS.select(),
-                    WordType::Adverb => ADVERBS.select(),
-                    WordType::Adjective => ADJECTIVES.select(),
-                    WordType::Preposition => PREPOSITIONS.select(),
-                    WordType::Determiner => DETERMINERS.select(),
-                    WordType::Conjunction => CONJUNCTIONS.select(),
-                };
-                return word;
-            }
-        }
+    pub word_type: WordType,
+    pub index: u32,
+    pub form: Form,
+}
+
+impl GeneType {
+    pub fn word(&self) -> &'static dyn Word {
+        collection(self.word_type).word(self.index)
+    }
+    /// The word inflected in the gene's form.
+    pub fn text(&self) -> Cow<'static, str> {
+        self.word().inflect(self.form)
+    }
+}
+
+/// Every word in the lexicon in every form it can take.
+pub fn all_genes() -> impl Iterator<Item = GeneType> {
+    WordType::ALL.into_iter().flat_map(|word_type| {
+        (0..collection(word_type).size()).flat_map(move |index| {
+            collection(word_type)
+                .word(index)
+                .forms()
+                .iter()
+                .map(move |&form| GeneType {
+                    word_type,
+                    index,
+                    form,
+                })
+        })
+    })
+}
+
+/// Every lexicon entry and form written as `text`, ignoring case.
+pub fn genes_written_as(text: &str) -> Vec<GeneType> {
+    all_genes()
+        .filter(|g| g.text().eq_ignore_ascii_case(text))
+        .collect()
+}
+
+pub fn select_word(request: &Request) -> GeneType {
+    select_word_from(request, &WORD_THRESHOLDS, &[])
+}
+
+/// A word for the position after the words `before` it, using the position rates from the
+/// config where it has them.
+pub fn select_word_after(request: &Request, before: &[GeneType]) -> GeneType {
+    let thresholds = match before.last() {
+        None => START_THRESHOLDS.as_ref(),
+        Some(previous) => AFTER_THRESHOLDS.get(&previous.word_type),
+    };
+    select_word_from(request, thresholds.unwrap_or(&WORD_THRESHOLDS), before)
+}
+
+fn select_word_from(
+    request: &Request,
+    thresholds: &[(f32, WordType)],
+    before: &[GeneType],
+) -> GeneType {
+    select_word_of_type(request, pick(thresholds), before)
+}
+
+/// `count` random sentences for the request.
+pub fn random_population(request: &Request, count: i32) -> Vec<Chromosome> {
+    (0..count).map(|_| Chromosome::random(request)).collect()
+}
+
+/// A word for the position after the words `before` it, from the template's slot when there
+/// is one.
+pub fn select_word_at(request: &Request, before: &[GeneType]) -> GeneType {
+    match &request.template {
+        Some(template) => template.slots[before.len()].select(request, before),
+        None => select_word_from(request, &WORD_THRESHOLDS, before),
+    }
+}
+
+/// A word of the type that isn't on the blocklist, to follow the words `before` it.
+pub fn select_word_of_type(
+    request: &Request,
+    word_type: WordType,
+    before: &[GeneType],
+) -> GeneType {
+    blocklist::select_allowed(|| sample_word_of_type(request, word_type, before))
+}
+
+fn sample_word_of_type(request: &Request, word_type: WordType, before: &[GeneType]) -> GeneType {
+    let index = collection(word_type).select();
+    let forms = collection(word_type).word(index).forms();
+    let form = select_form(request, forms, tense::is_finite_after(before));
+    GeneType {
+        word_type,
+        index,
+        form,
+    }
+}
+
+/// Picks one of a word's forms, leaving out the ones that can't be in the requested tense
+/// unless that is all the word has.
+pub fn select_form(request: &Request, forms: &[Form], finite: bool) -> Form {
+    let forms = allowed_forms(request, forms, finite);
+    forms[rng::random_range(0..forms.len())]
+}
+
+/// The forms the request's tense allows, or all of them when it allows none. The tense only
+/// limits a `finite` verb, one after an auxiliary or modal can take any form ("did eat").
+pub fn allowed_forms(request: &Request, forms: &[Form], finite: bool) -> Vec<Form> {
+    let allowed: Vec<Form> = match request.tense {
+        Some(tense) if finite => forms.iter().copied().filter(|&f| tense.allows(f)).collect(),
+        _ => Vec::new(),
+    };
+    if allowed.is_empty() {
+        forms.to_vec()
+    } else {
+        allowed
     }
 }
 
 impl Generate for GeneType {
     fn generate() -> Self {
-        GeneType {
-            word: select_word(),
-        }
+        select_word(&DEFAULT)
     }
 }
 
-impl<'a> Mutate for GeneType {
+impl Mutate for GeneType {
     fn mutate(&mut self) {
-        if rand::random_range(0.00..1.00) <= CONFIG.mutation_probability {
-            self.word = select_word()
+        if rng::random_range(0.00..1.00) <= CONFIG.mutation_probability {
+            *self = select_word(&DEFAULT)
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Chromosome {
     pub genes: Vec<GeneType>,
     pub fitness: Option<f32>,
+    pub objectives: Vec<f32>,
 }
 
+/// genetica's traits can't be given a request so they use the one from the config, the
+/// strategies call the methods taking a request instead.
 impl Individual for Chromosome {
     type GeneType = GeneType;
     fn new() -> Self {
-        let genes: Vec<GeneType> = (0..CONFIG.word_count)
-            .map(|_| GeneType::generate())
-            .collect();
-        Chromosome {
-            genes,
-            fitness: None,
-        }
+        Chromosome::random(&DEFAULT)
     }
     fn mutate_genes(&mut self) {
-        self.genes_mut().iter_mut().for_each(|g| g.mutate());
+        self.mutate(&DEFAULT);
     }
     fn fitness(&self) -> Option<f32> {
         self.fitness
@@ -80,27 +171,115 @@ impl Individual for Chromosome {
         &mut self.fitness
     }
     fn calculate_fitness(&mut self) {
-        let structure_error_count: f32 = if CONFIG.use_structure_fitness {
-            STRUCTURE
+        self.evaluate(&DEFAULT);
+    }
+}
+
+impl Chromosome {
+    pub fn from_genes(genes: Vec<GeneType>) -> Self {
+        Chromosome {
+            genes,
+            fitness: None,
+            objectives: Vec::new(),
+        }
+    }
+
+    /// A random sentence for the request, with its required words placed.
+    pub fn random(request: &Request) -> Self {
+        let mut genes: Vec<GeneType> = Vec::with_capacity(request.word_count());
+        for position in 0..request.word_count() {
+            let gene = match &request.template {
+                Some(template) => template.slots[position].select(request, &genes),
+                None => select_word_after(request, &genes),
+            };
+            genes.push(gene);
+        }
+        if request.template.is_none() {
+            required::place(
+                &mut genes,
+                &request.required_words,
+                request.ordered,
+                &request.structure,
+            );
+        }
+        Chromosome::from_genes(genes)
+    }
+
+    /// Replaces each word with the mutation probability, keeping a template's slots.
+    pub fn mutate(&mut self, request: &Request) {
+        for position in 0..self.genes.len() {
+            if rng::random_range(0.00..1.00) <= CONFIG.mutation_probability {
+                self.genes[position] = select_word_at(request, &self.genes[..position]);
+            }
+        }
+    }
+
+    /// Calculates the objectives and the weighted fitness for the request.
+    pub fn evaluate(&mut self, request: &Request) {
+        self.objectives = self.calculate_objectives(request);
+        let fitness: f32 = self
+            .objectives
+            .iter()
+            .zip(Self::enabled_objectives(request))
+            .map(|(objective, (_, weight))| objective * weight)
+            .sum();
+        self.fitness = Some(fitness)
+    }
+
+    /// Names and weights of the enabled fitness components, in the same order as `objectives`.
+    pub fn enabled_objectives(request: &Request) -> Vec<(&'static str, f32)> {
+        let mut enabled = Vec::new();
+        if CONFIG.use_structure_fitness {
+            enabled.push(("structure", CONFIG.structure_weight));
+        }
+        if CONFIG.use_grammar_fitness {
+            enabled.push(("grammar", CONFIG.grammar_weight));
+        }
+        if CONFIG.use_semantic_fitness {
+            enabled.push(("semantic", CONFIG.semantic_weight));
+        }
+        if !request.history.is_empty() {
+            enabled.push(("coherence", CONFIG.coherence_weight));
+        }
+        if !request.required_words.is_empty() {
+            enabled.push(("required words", CONFIG.required_words_weight));
+        }
+        enabled
+    }
+
+    /// Calculates each enabled fitness component unweighted, higher is better. A sentence
+    /// that matches the blocklist scores 0.00 on all of them.
+    fn calculate_objectives(&self, request: &Request) -> Vec<f32> {
+        if blocklist::is_blocked(&self.genes, request) {
+            return vec![0.00; Self::enabled_objectives(request).len()];
+        }
+        let mut objectives = Vec::new();
+        if CONFIG.use_structure_fitness {
+            let structure_error_count = request
+                .structure
                 .iter()
                 .zip(&self.genes)
-                .filter(|(wt, gt)| gt.word.word_type() != **wt)
-                .count() as f32
-        } else {
-            0.0
-        };
-
-        let mut previous_word: Option<WordType> = None;
-        let mut grammar_count: f32 = 0.0;
+                .filter(|(wt, gt)| gt.word_type != **wt)
+                .count() as f32;
+            objectives.push(1.0 / (structure_error_count + 1.0));
+        }
         if CONFIG.use_grammar_fitness {
-            self.genes.iter().for_each(|gt| {
-                grammar_count += gt.word.grammar(previous_word);
-                previous_word = Some(gt.word.word_type());
-            })
-        };
-        let structure_fitness: f32 = CONFIG.structure_weight / (structure_error_count + 1.0);
-        let grammar_fitness: f32 = (CONFIG.grammar_weight * (grammar_count + 1.0)).max(0.0);
-        self.fitness = Some(structure_fitness + grammar_fitness)
+            objectives.push(grammar::score(&self.genes, request));
+        }
+        if CONFIG.use_semantic_fitness {
+            objectives.push(semantic::score(&self.genes));
+        }
+        if !request.history.is_empty() {
+            objectives.push(coherence::score(&self.genes, &request.history));
+        }
+        if !request.required_words.is_empty() {
+            objectives.push(required::score(
+                &self.genes,
+                &request.required_words,
+                request.ordered,
+            ));
+        }
+        objectives
     }
 }
 
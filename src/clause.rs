@@ -0,0 +1,161 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::{
+    WordType,
+    chromosome::GeneType,
+    rng,
+    structures::CLAUSE_STRUCTURES,
+    words::{CONJUNCTIONS, ConjunctionType},
+};
+
+/// How much a sentence is penalised for each clause its shape needs that is missing a subject
+/// or verb, or for missing the conjunction that joins them.
+const CLAUSE_PENALTY: f32 = 0.4;
+/// How much a correlative conjunction is penalised when its pair doesn't follow it.
+const UNMATCHED_PAIR_PENALTY: f32 = 0.5;
+
+/// How many clauses a sentence has and how they are joined.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum Shape {
+    #[default]
+    Simple,
+    /// Two independent clauses joined by a coordinating conjunction and a comma.
+    Compound,
+    /// A main clause followed by a subordinate clause.
+    Complex,
+    /// Two clauses joined by a correlative pair ("either ... or").
+    Correlative,
+}
+
+impl FromStr for Shape {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "simple" => Ok(Shape::Simple),
+            "compound" => Ok(Shape::Compound),
+            "complex" => Ok(Shape::Complex),
+            "correlative" => Ok(Shape::Correlative),
+            _ => Err(format!(
+                "Unknown shape {s}, expected simple, compound, complex or correlative"
+            )),
+        }
+    }
+}
+
+impl Shape {
+    pub fn conjunction_type(self) -> Option<ConjunctionType> {
+        match self {
+            Shape::Simple => None,
+            Shape::Compound => Some(ConjunctionType::Coordinating),
+            Shape::Complex => Some(ConjunctionType::Subordinating),
+            Shape::Correlative => Some(ConjunctionType::Correlative),
+        }
+    }
+
+    /// The fewest words a sentence of this shape can have.
+    pub fn minimum_word_count(self) -> usize {
+        match self {
+            Shape::Simple => 1,
+            Shape::Compound | Shape::Complex => 5,
+            Shape::Correlative => 6,
+        }
+    }
+
+    /// Two clause structures joined by conjunctions, splitting the word count between them.
+    pub fn structure(self, word_count: usize) -> Vec<WordType> {
+        let conjunctions = match self {
+            Shape::Simple => return Vec::new(),
+            Shape::Compound | Shape::Complex => 1,
+            Shape::Correlative => 2,
+        };
+        let clause_words = word_count.saturating_sub(conjunctions);
+        let first = clause(clause_words / 2);
+        let second = clause(clause_words - clause_words / 2);
+        let mut structure = Vec::with_capacity(word_count);
+        if self == Shape::Correlative {
+            structure.push(WordType::Conjunction);
+        }
+        structure.extend_from_slice(first);
+        structure.push(WordType::Conjunction);
+        structure.extend_from_slice(second);
+        structure
+    }
+
+    /// Penalises a sentence whose clauses don't fit its shape. Correlative conjunctions need
+    /// their pair after them whatever the shape.
+    pub fn penalty(self, genes: &[GeneType]) -> f32 {
+        let unmatched = genes
+            .iter()
+            .enumerate()
+            .filter(|&(i, &g)| pair(g).is_some_and(|pair| find_after(genes, i, pair).is_none()))
+            .count() as f32
+            * UNMATCHED_PAIR_PENALTY;
+        let Some(conjunction_type) = self.conjunction_type() else {
+            return unmatched;
+        };
+        let split = if conjunction_type == ConjunctionType::Correlative {
+            genes
+                .first()
+                .and_then(|&first| pair(first))
+                .and_then(|pair| find_after(genes, 0, pair))
+                .map(|p| (1, p))
+        } else {
+            genes
+                .iter()
+                .position(|&g| conjunction_type_of(g) == Some(conjunction_type))
+                .map(|p| (0, p))
+        };
+        let Some((start, position)) = split else {
+            return unmatched + CLAUSE_PENALTY;
+        };
+        let clauses = [&genes[start..position], &genes[position + 1..]];
+        unmatched + clauses.iter().filter(|c| !is_clause(c)).count() as f32 * CLAUSE_PENALTY
+    }
+}
+
+fn clause(length: usize) -> &'static [WordType] {
+    let matching: Vec<&'static [WordType]> = CLAUSE_STRUCTURES
+        .iter()
+        .copied()
+        .filter(|s| s.len() == length)
+        .collect();
+    if matching.is_empty() {
+        return CLAUSE_STRUCTURES[CLAUSE_STRUCTURES.len() - 1];
+    }
+    matching[rng::random_range(0..matching.len())]
+}
+
+pub fn conjunction_type_of(gene: GeneType) -> Option<ConjunctionType> {
+    match gene.word_type {
+        WordType::Conjunction => Some(CONJUNCTIONS.words[gene.index as usize].r#type),
+        _ => None,
+    }
+}
+
+/// The word a correlative conjunction has to be matched with.
+fn pair(gene: GeneType) -> Option<&'static str> {
+    if conjunction_type_of(gene) != Some(ConjunctionType::Correlative) {
+        return None;
+    }
+    CONJUNCTIONS.words[gene.index as usize]
+        .attributes
+        .pair
+        .as_deref()
+}
+
+fn find_after(genes: &[GeneType], index: usize, word: &str) -> Option<usize> {
+    genes[index + 1..]
+        .iter()
+        .position(|g| g.text() == word)
+        .map(|p| p + index + 1)
+}
+
+/// A clause has a subject with a verb somewhere after it.
+pub fn is_clause(genes: &[GeneType]) -> bool {
+    genes
+        .iter()
+        .position(|g| matches!(g.word_type, WordType::Noun | WordType::Pronoun))
+        .is_some_and(|s| genes[s + 1..].iter().any(|g| g.word_type == WordType::Verb))
+}
@@ -0,0 +1,70 @@
+use std::{fmt::Display, path::PathBuf, process, str::FromStr};
+
+use sengen::{
+    clause::Shape,
+    mood::Mood,
+    strategy::Strategy,
+    tense::{Aspect, Tense},
+};
+
+#[derive(Debug, Default)]
+pub struct Args {
+    pub strategy: Strategy,
+    pub compare: bool,
+    pub tense: Option<Tense>,
+    pub aspect: Option<Aspect>,
+    pub mood: Option<Mood>,
+    pub shape: Option<Shape>,
+    pub paragraph: Option<usize>,
+    pub required_words: Option<Vec<String>>,
+    pub ordered: bool,
+    pub template: Option<String>,
+    pub explain: bool,
+    /// The corpus to learn rates from instead of generating sentences.
+    pub fit_rates: Option<PathBuf>,
+}
+
+impl Args {
+    pub fn parse() -> Self {
+        let mut args = Args::default();
+        let mut arguments = std::env::args().skip(1);
+        while let Some(argument) = arguments.next() {
+            match argument.as_str() {
+                "--strategy" => args.strategy = parse_value(&argument, arguments.next()),
+                "--tense" => args.tense = Some(parse_value(&argument, arguments.next())),
+                "--aspect" => args.aspect = Some(parse_value(&argument, arguments.next())),
+                "--mood" => args.mood = Some(parse_value(&argument, arguments.next())),
+                "--shape" => args.shape = Some(parse_value(&argument, arguments.next())),
+                "--paragraph" => args.paragraph = Some(parse_value(&argument, arguments.next())),
+                "--require" => {
+                    let words: String = parse_value(&argument, arguments.next());
+                    args.required_words = Some(words.split(',').map(str::to_string).collect());
+                }
+                "--ordered" => args.ordered = true,
+                "--template" => args.template = Some(parse_value(&argument, arguments.next())),
+                "--explain" => args.explain = true,
+                "--compare" => args.compare = true,
+                "fit-rates" => args.fit_rates = Some(parse_value(&argument, arguments.next())),
+                _ => {
+                    eprintln!("Unknown argument {argument}");
+                    process::exit(1);
+                }
+            }
+        }
+        args
+    }
+}
+
+fn parse_value<T: FromStr>(argument: &str, value: Option<String>) -> T
+where
+    T::Err: Display,
+{
+    let value = value.unwrap_or_else(|| {
+        eprintln!("{argument} needs a value");
+        process::exit(1);
+    });
+    value.parse().unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    })
+}
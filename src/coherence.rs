@@ -0,0 +1,69 @@
+use crate::{
+    WordType,
+    chromosome::GeneType,
+    grammar::noun_number,
+    words::{Number, PRONOUNS, Person, PronounType},
+};
+
+/// How much a reference back through a repeated noun is worth next to one through a pronoun.
+const REPEATED_NOUN_REFERENCE: f32 = 0.6;
+
+/// Scores how well a sentence follows the sentences before it in a paragraph, 1.00 is best.
+/// Half of it is referring back to a noun introduced earlier, preferably with a pronoun that
+/// agrees with it in number, and half is having a different structure to the previous sentence.
+pub fn score(genes: &[GeneType], history: &[Vec<GeneType>]) -> f32 {
+    let Some(previous) = history.last() else {
+        return 1.0;
+    };
+    (reference(genes, history) + variety(genes, previous)) / 2.0
+}
+
+/// 1.00 for a pronoun that can stand for a noun from an earlier sentence, less for repeating
+/// the noun itself and 0.00 for neither.
+fn reference(genes: &[GeneType], history: &[Vec<GeneType>]) -> f32 {
+    let introduced: Vec<GeneType> = history
+        .iter()
+        .flatten()
+        .filter(|h| h.word_type == WordType::Noun)
+        .copied()
+        .collect();
+    let refers_back = |gene: &GeneType| {
+        third_person_number(*gene)
+            .is_some_and(|number| introduced.iter().any(|&n| noun_number(n) == number))
+    };
+    if genes.iter().any(refers_back) {
+        1.0
+    } else if genes
+        .iter()
+        .any(|g| g.word_type == WordType::Noun && introduced.iter().any(|n| n.index == g.index))
+    {
+        REPEATED_NOUN_REFERENCE
+    } else {
+        0.0
+    }
+}
+
+/// The number of personal pronouns like "it" and "they" that can stand for a noun from an
+/// earlier sentence, which the noun has to agree with.
+fn third_person_number(gene: GeneType) -> Option<Number> {
+    if gene.word_type != WordType::Pronoun {
+        return None;
+    }
+    let pronoun = &PRONOUNS.words[gene.index as usize];
+    (pronoun.r#type == PronounType::Personal && pronoun.attributes.person == Person::Third)
+        .then_some(pronoun.attributes.number)
+}
+
+/// The share of positions whose word type differs from the previous sentence.
+fn variety(genes: &[GeneType], previous: &[GeneType]) -> f32 {
+    let length = genes.len().max(previous.len());
+    if length == 0 {
+        return 1.0;
+    }
+    let same = genes
+        .iter()
+        .zip(previous)
+        .filter(|(g, p)| g.word_type == p.word_type)
+        .count();
+    1.0 - same as f32 / length as f32
+}
@@ -0,0 +1,150 @@
+use std::cmp::min;
+
+use genetica::individual::DynamicLengthIndividual;
+use serde::Deserialize;
+
+use crate::{CONFIG, WordType, chromosome::Chromosome, rng};
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum CrossoverOperator {
+    #[default]
+    SinglePoint,
+    TwoPoint,
+    Uniform,
+    PhraseBoundary,
+}
+
+/// Crosses over two parents using the operator picked in the config.
+pub fn crossover(parent1: &Chromosome, parent2: &Chromosome) -> (Chromosome, Chromosome) {
+    let probability = CONFIG.crossover_probability;
+    match CONFIG.crossover_operator {
+        CrossoverOperator::SinglePoint => single_point_crossover(parent1, parent2, probability),
+        CrossoverOperator::TwoPoint => two_point_crossover(parent1, parent2, probability),
+        CrossoverOperator::Uniform => uniform_crossover(parent1, parent2, probability),
+        CrossoverOperator::PhraseBoundary => {
+            phrase_boundary_crossover(parent1, parent2, probability)
+        }
+    }
+}
+
+// Same as genetica's dynamic length crossovers but drawing from the seedable generator
+
+/// Preforms a single point crossover on two chromosomes based on the crossover probability.
+pub fn single_point_crossover<I>(parent1: &I, parent2: &I, crossover_probability: f32) -> (I, I)
+where
+    I: DynamicLengthIndividual,
+{
+    if rng::random_range(0.00..1.00) <= crossover_probability {
+        let min_length = min(parent1.genes().len(), parent2.genes().len());
+        if min_length == 0 {
+            return (parent1.clone(), parent2.clone());
+        };
+        let crossover_point = rng::random_range(0..min_length);
+
+        let mut child1 = parent1.clone();
+        let mut child2 = parent2.clone();
+
+        child1.genes_mut().truncate(crossover_point);
+        child1
+            .genes_mut()
+            .extend_from_slice(&parent2.genes()[crossover_point..]);
+
+        child2.genes_mut().truncate(crossover_point);
+        child2
+            .genes_mut()
+            .extend_from_slice(&parent1.genes()[crossover_point..]);
+
+        (child1, child2)
+    } else {
+        (parent1.clone(), parent2.clone())
+    }
+}
+
+/// Preforms a two point crossover on two chromosomes based on the crossover probability.
+pub fn two_point_crossover<I>(parent1: &I, parent2: &I, crossover_probability: f32) -> (I, I)
+where
+    I: DynamicLengthIndividual,
+{
+    if rng::random_range(0.00..1.00) <= crossover_probability {
+        let min_length = min(parent1.genes().len(), parent2.genes().len());
+        if min_length < 2 {
+            return (parent1.clone(), parent2.clone());
+        }
+
+        let point1 = rng::random_range(0..min_length - 1);
+        let point2 = rng::random_range(point1 + 1..min_length);
+
+        let mut child1 = parent1.clone();
+        let mut child2 = parent2.clone();
+
+        child1.genes_mut()[point1..point2].swap_with_slice(&mut child2.genes_mut()[point1..point2]);
+
+        return (child1, child2);
+    }
+
+    (parent1.clone(), parent2.clone())
+}
+
+/// Swaps each gene between the two parents with a 50% chance based on the crossover probability.
+pub fn uniform_crossover<I>(parent1: &I, parent2: &I, crossover_probability: f32) -> (I, I)
+where
+    I: DynamicLengthIndividual,
+{
+    let mut child1 = parent1.clone();
+    let mut child2 = parent2.clone();
+    if rng::random_range(0.00..1.00) <= crossover_probability {
+        let min_length = min(parent1.genes().len(), parent2.genes().len());
+        for i in 0..min_length {
+            if rng::random_bool(0.5) {
+                std::mem::swap(&mut child1.genes_mut()[i], &mut child2.genes_mut()[i]);
+            }
+        }
+    }
+    (child1, child2)
+}
+
+/// Preforms a single point crossover that only cuts where a phrase starts in both parents,
+/// so noun phrases are never split between the children.
+pub fn phrase_boundary_crossover(
+    parent1: &Chromosome,
+    parent2: &Chromosome,
+    crossover_probability: f32,
+) -> (Chromosome, Chromosome) {
+    if rng::random_range(0.00..1.00) <= crossover_probability {
+        let min_length = min(parent1.genes.len(), parent2.genes.len());
+        let boundaries: Vec<usize> = (1..min_length)
+            .filter(|&i| {
+                starts_phrase(parent1.genes[i].word_type)
+                    && starts_phrase(parent2.genes[i].word_type)
+            })
+            .collect();
+        if boundaries.is_empty() {
+            return (parent1.clone(), parent2.clone());
+        }
+        let crossover_point = boundaries[rng::random_range(0..boundaries.len())];
+
+        let mut child1 = parent1.clone();
+        let mut child2 = parent2.clone();
+
+        child1.genes.truncate(crossover_point);
+        child1
+            .genes
+            .extend_from_slice(&parent2.genes[crossover_point..]);
+
+        child2.genes.truncate(crossover_point);
+        child2
+            .genes
+            .extend_from_slice(&parent1.genes[crossover_point..]);
+
+        (child1, child2)
+    } else {
+        (parent1.clone(), parent2.clone())
+    }
+}
+
+fn starts_phrase(word_type: WordType) -> bool {
+    matches!(
+        word_type,
+        WordType::Determiner | WordType::Pronoun | WordType::Verb | WordType::Conjunction
+    )
+}
@@ -0,0 +1,191 @@
+use std::{collections::HashMap, fmt::Write};
+
+use crate::{
+    WordType,
+    chromosome::all_genes,
+    words::{Countability, NOUNS, Tangibility, collection},
+};
+
+/// How often each word type, subtype and noun attribute was tagged in a corpus. Words that
+/// could be more than one lexicon entry count a share towards each of them.
+#[derive(Default)]
+struct Counts {
+    word_types: HashMap<WordType, f32>,
+    subtypes: HashMap<(WordType, String), f32>,
+    tangibilities: HashMap<&'static str, f32>,
+    countabilities: HashMap<&'static str, f32>,
+}
+
+/// Tags every word of a corpus with the lexicon and returns a config fragment with the rates
+/// they were found at, along with how many words were and weren't in the lexicon.
+pub fn fit_rates(corpus: &str) -> (String, usize, usize) {
+    let mut lexicon: HashMap<String, Vec<(WordType, u32)>> = HashMap::new();
+    for gene in all_genes() {
+        let entries = lexicon.entry(gene.text().to_lowercase()).or_default();
+        if !entries.contains(&(gene.word_type, gene.index)) {
+            entries.push((gene.word_type, gene.index));
+        }
+    }
+
+    let mut counts = Counts::default();
+    let (mut tagged, mut untagged) = (0, 0);
+    let tokens = corpus
+        .split(|c: char| !c.is_alphabetic() && c != '\'')
+        .filter(|t| !t.is_empty());
+    for token in tokens {
+        let Some(entries) = lexicon.get(&token.to_lowercase()) else {
+            untagged += 1;
+            continue;
+        };
+        tagged += 1;
+        let share = 1.0 / entries.len() as f32;
+        for &(word_type, index) in entries {
+            counts.add(word_type, index, share);
+        }
+    }
+    (counts.fragment(), tagged, untagged)
+}
+
+impl Counts {
+    fn add(&mut self, word_type: WordType, index: u32, share: f32) {
+        *self.word_types.entry(word_type).or_default() += share;
+        *self
+            .subtypes
+            .entry((word_type, collection(word_type).subtype_name(index)))
+            .or_default() += share;
+        if word_type != WordType::Noun {
+            return;
+        }
+        let noun = &NOUNS.words[index as usize].attributes;
+        let tangibility = match noun.tangibility {
+            Tangibility::Concrete => "concrete",
+            Tangibility::Abstract => "abstract",
+        };
+        *self.tangibilities.entry(tangibility).or_default() += share;
+        let countabilities: &[&str] = match noun.countability {
+            Countability::Countable => &["countable"],
+            Countability::Uncountable => &["uncountable"],
+            Countability::Both => &["countable", "uncountable"],
+        };
+        for countability in countabilities {
+            *self.countabilities.entry(countability).or_default() +=
+                share / countabilities.len() as f32;
+        }
+    }
+
+    fn fragment(&self) -> String {
+        let mut fragment = String::new();
+        let word_types = WordType::ALL
+            .iter()
+            .map(|wt| (key(wt), self.word_types.get(wt).copied().unwrap_or(0.0)))
+            .collect();
+        write_table(&mut fragment, "word_type_rates", word_types);
+        for word_type in WordType::ALL {
+            let subtypes = collection(word_type)
+                .subtype_names()
+                .into_iter()
+                .map(|name| {
+                    let count = self.subtypes.get(&(word_type, name.clone()));
+                    (name.to_lowercase(), count.copied().unwrap_or(0.0))
+                })
+                .collect();
+            let table = match word_type {
+                WordType::Noun => "noun_rates.type_rates".to_string(),
+                _ => format!("{}_type_rates", key(&word_type)),
+            };
+            write_table(&mut fragment, &table, subtypes);
+            if word_type == WordType::Noun {
+                let tangibilities = ["concrete", "abstract"].map(|name| {
+                    let count = self.tangibilities.get(name);
+                    (name.to_string(), count.copied().unwrap_or(0.0))
+                });
+                write_table(
+                    &mut fragment,
+                    "noun_rates.tangibility_rates",
+                    tangibilities.to_vec(),
+                );
+                let countabilities = ["countable", "uncountable"].map(|name| {
+                    let count = self.countabilities.get(name);
+                    (name.to_string(), count.copied().unwrap_or(0.0))
+                });
+                write_table(
+                    &mut fragment,
+                    "noun_rates.countability_rates",
+                    countabilities.to_vec(),
+                );
+            }
+        }
+        fragment
+    }
+}
+
+fn key(word_type: &WordType) -> String {
+    format!("{word_type:?}").to_lowercase()
+}
+
+/// Writes counts as a table of rates adding up to 1.00, or even rates if none were seen. Rates
+/// are rounded to thousandths and what the rounding gained or lost goes to the largest, so the
+/// table still adds up to exactly 1.000 for `strict_rates`.
+fn write_table(fragment: &mut String, table: &str, counts: Vec<(String, f32)>) {
+    let total: f32 = counts.iter().map(|(_, count)| count).sum();
+    let _ = writeln!(fragment, "[{table}]");
+    if total == 0.0 {
+        let _ = writeln!(fragment, "# None were found in the corpus");
+    }
+    let mut thousandths: Vec<i32> = counts
+        .iter()
+        .map(|(_, count)| {
+            let rate = if total > 0.0 {
+                count / total
+            } else {
+                1.0 / counts.len() as f32
+            };
+            (rate * 1000.0).round() as i32
+        })
+        .collect();
+    let remainder = 1000 - thousandths.iter().sum::<i32>();
+    if let Some(largest) = (0..thousandths.len()).max_by_key(|&i| thousandths[i]) {
+        thousandths[largest] += remainder;
+    }
+    for ((name, _), rate) in counts.iter().zip(thousandths) {
+        let _ = writeln!(fragment, "{name} = {:.3}", rate as f32 / 1000.0);
+    }
+    fragment.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rates::{Rates, rates_error};
+
+    struct Table(Vec<f32>);
+    impl Rates for Table {
+        fn values(&self) -> Vec<f32> {
+            self.0.clone()
+        }
+    }
+
+    #[test]
+    fn rounded_tables_pass_strict_rates() {
+        let mut fragment = String::new();
+        // Six sixths each round up to 0.167, which alone would add up to 1.002
+        let counts = (0..6).map(|i| (format!("type{i}"), 1.0)).collect();
+        write_table(&mut fragment, "adverb_type_rates", counts);
+        write_table(
+            &mut fragment,
+            "empty_rates",
+            vec![("a".to_string(), 0.0); 3],
+        );
+        fragment.push_str(&fit_rates("the cat quickly ate a tasty cake and she ran home").0);
+
+        for table in fragment.split('[').skip(1) {
+            let (name, rows) = table.split_once(']').unwrap();
+            let values = rows
+                .lines()
+                .filter_map(|row| row.split_once(" = "))
+                .map(|(_, rate)| rate.parse().unwrap())
+                .collect();
+            assert_eq!(rates_error(name, &Table(values), true), None);
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use genetica::population::sort_population_descending;
+use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
+
+use crate::{
+    CONFIG,
+    chromosome::{Chromosome, random_population},
+    crossover::crossover,
+    hall_of_fame::HallOfFame,
+    request::Request,
+};
+
+/// Runs the genetic algorithm, recording every generation in the hall of fame.
+pub fn run(request: &Request, hall_of_fame: &mut HallOfFame) {
+    let mut population: Vec<Chromosome> = random_population(request, CONFIG.population_count);
+
+    for _ in 0..CONFIG.generations {
+        population = next_generation(request, &population, CONFIG.population_count);
+        hall_of_fame.update(&population, request);
+    }
+}
+
+/// Breeds the two best chromosomes and fills the rest of the population with new ones,
+/// returning the new population sorted by fitness.
+pub fn next_generation(
+    request: &Request,
+    population: &[Chromosome],
+    population_count: i32,
+) -> Vec<Chromosome> {
+    let parent1 = &population[0];
+    let parent2 = &population[1];
+    let (mut child1, mut child2) = crossover(parent1, parent2);
+    child1.mutate(request);
+    child2.mutate(request);
+
+    let mut new_population: Vec<Chromosome> = random_population(request, population_count - 4);
+
+    new_population.push(child1);
+    new_population.push(child2);
+    new_population.push(parent1.clone());
+    new_population.push(parent2.clone());
+    new_population
+        .par_iter_mut()
+        .for_each(|c| c.evaluate(request));
+    sort_population_descending(&mut new_population);
+    new_population
+}
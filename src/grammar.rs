@@ -0,0 +1,223 @@
+use crate::{
+    WordType,
+    chromosome::GeneType,
+    mood::{self, Mood},
+    morphology::Form,
+    request::Request,
+    tense,
+    words::{Case, NUMERALS, Number, PRONOUNS, Person, VERBS},
+};
+
+/// How much an inverted question is penalised for having no subject.
+const MISSING_SUBJECT_PENALTY: f32 = 0.5;
+
+/// How much an interjection is penalised for being anywhere but the start of the sentence.
+const MISPLACED_INTERJECTION_PENALTY: f32 = 0.6;
+
+/// How much a word of the first type is penalised for following a word of the second type.
+#[rustfmt::skip]
+const GRAMMAR_PENALTIES: &[(WordType, WordType, f32)] = &[
+    (WordType::Noun, WordType::Noun, 0.4),
+    (WordType::Noun, WordType::Verb, 0.2),
+    (WordType::Noun, WordType::Adverb, 0.3),
+    (WordType::Noun, WordType::Pronoun, 0.4),
+    (WordType::Pronoun, WordType::Noun, 0.4),
+    (WordType::Pronoun, WordType::Pronoun, 0.4),
+    (WordType::Pronoun, WordType::Adjective, 0.5),
+    (WordType::Pronoun, WordType::Determiner, 0.6),
+    (WordType::Verb, WordType::Verb, 0.4),
+    (WordType::Verb, WordType::Determiner, 0.3),
+    (WordType::Verb, WordType::Preposition, 0.4),
+    (WordType::Verb, WordType::Adjective, 0.2),
+    (WordType::Adverb, WordType::Noun, 0.5),
+    (WordType::Adverb, WordType::Adverb, 0.2),
+    (WordType::Adverb, WordType::Determiner, 0.5),
+    (WordType::Adverb, WordType::Preposition, 0.4),
+    (WordType::Adjective, WordType::Noun, 0.3),
+    (WordType::Adjective, WordType::Verb, 0.2),
+    (WordType::Adjective, WordType::Preposition, 0.4),
+    (WordType::Preposition, WordType::Verb, 0.1),
+    (WordType::Preposition, WordType::Adverb, 0.3),
+    (WordType::Preposition, WordType::Determiner, 0.5),
+    (WordType::Preposition, WordType::Preposition, 0.4),
+    (WordType::Preposition, WordType::Adjective, 0.2),
+    (WordType::Determiner, WordType::Noun, 0.5),
+    (WordType::Determiner, WordType::Verb, 0.4),
+    (WordType::Determiner, WordType::Adverb, 0.3),
+    (WordType::Determiner, WordType::Determiner, 0.6),
+    (WordType::Determiner, WordType::Adjective, 0.3),
+    (WordType::Conjunction, WordType::Adverb, 0.2),
+    (WordType::Conjunction, WordType::Determiner, 0.5),
+    (WordType::Conjunction, WordType::Preposition, 0.3),
+    (WordType::Conjunction, WordType::Conjunction, 0.4),
+    (WordType::Numeral, WordType::Noun, 0.3),
+    (WordType::Numeral, WordType::Pronoun, 0.4),
+    (WordType::Numeral, WordType::Adjective, 0.3),
+    (WordType::Numeral, WordType::Numeral, 0.4),
+    (WordType::Verb, WordType::Numeral, 0.3),
+    (WordType::Adverb, WordType::Numeral, 0.3),
+    (WordType::Pronoun, WordType::Numeral, 0.4),
+    (WordType::Preposition, WordType::Numeral, 0.3),
+];
+
+pub fn grammar_penalty(word_type: WordType, previous_word_type: WordType) -> f32 {
+    GRAMMAR_PENALTIES
+        .iter()
+        .find(|&&(current, previous, _)| current == word_type && previous == previous_word_type)
+        .map(|&(_, _, penalty)| penalty)
+        .unwrap_or(0.00)
+}
+
+/// How much `current` is penalised for following `previous`, looking at the words themselves
+/// as well as their types. In an inverted question the subject comes after the auxiliary, so
+/// case and agreement are checked by `inversion_penalty` instead.
+pub fn penalty(previous: GeneType, current: GeneType, inverted: bool) -> f32 {
+    // An interjection is separated from the rest of the sentence by a comma.
+    if previous.word_type == WordType::Interjection {
+        return 0.00;
+    }
+    if is_verb_chain(previous, current) {
+        return 0.00;
+    }
+    let mut penalty = grammar_penalty(current.word_type, previous.word_type)
+        + number_penalty(previous, current)
+        + verb_form_penalty(previous, current);
+    if !inverted {
+        penalty += case_penalty(previous, current) + agreement_penalty(previous, current);
+    }
+    penalty
+}
+
+/// A question that opens with an auxiliary needs a subject next that the auxiliary agrees
+/// with, and the verb after the subject has to be one the auxiliary chains onto ("Does the cat
+/// eat?").
+fn inversion_penalty(genes: &[GeneType]) -> f32 {
+    let auxiliary = genes[0];
+    let Some(position) = genes
+        .iter()
+        .position(|g| matches!(g.word_type, WordType::Noun | WordType::Pronoun))
+    else {
+        return MISSING_SUBJECT_PENALTY;
+    };
+    let subject = genes[position];
+    let mut penalty = agreement_penalty(subject, auxiliary);
+    if subject.word_type == WordType::Pronoun
+        && matches!(pronoun_case(subject), Case::Object | Case::Possessive)
+    {
+        penalty += 0.4;
+    }
+    if let Some(verb) = genes[position + 1..]
+        .iter()
+        .find(|g| g.word_type == WordType::Verb)
+        && !VERBS.words[auxiliary.index as usize].chains_onto(auxiliary.form, verb.form)
+    {
+        penalty += 0.4;
+    }
+    penalty
+}
+
+/// An auxiliary or modal followed by a form it takes, like "can run" or "has eaten", is one verb.
+fn is_verb_chain(previous: GeneType, current: GeneType) -> bool {
+    previous.word_type == WordType::Verb
+        && current.word_type == WordType::Verb
+        && VERBS.words[previous.index as usize].chains_onto(previous.form, current.form)
+}
+
+/// Participles need an auxiliary before them, and a gerund straight after its subject is
+/// missing one ("she eating").
+fn verb_form_penalty(previous: GeneType, current: GeneType) -> f32 {
+    if current.word_type != WordType::Verb {
+        return 0.00;
+    }
+    match (current.form, previous.word_type) {
+        (Form::Participle, _) => 0.3,
+        (Form::Gerund, WordType::Noun | WordType::Pronoun) => 0.3,
+        _ => 0.00,
+    }
+}
+
+/// Cardinal numerals have to agree with the number of the noun they count.
+fn number_penalty(previous: GeneType, current: GeneType) -> f32 {
+    if previous.word_type != WordType::Numeral || current.word_type != WordType::Noun {
+        return 0.00;
+    }
+    match NUMERALS.words[previous.index as usize].attributes.number {
+        Some(number) if number != noun_number(current) => 0.5,
+        _ => 0.00,
+    }
+}
+
+/// A present tense verb takes "-s" after a third person singular subject and the base form
+/// after any other ("she eats", "they eat").
+fn agreement_penalty(previous: GeneType, current: GeneType) -> f32 {
+    if current.word_type != WordType::Verb || VERBS.words[current.index as usize].is_modal() {
+        return 0.00;
+    }
+    let third_person_singular = match previous.word_type {
+        WordType::Noun => noun_number(previous) == Number::Singular,
+        WordType::Pronoun => {
+            let attributes = &PRONOUNS.words[previous.index as usize].attributes;
+            attributes.person == Person::Third && attributes.number == Number::Singular
+        }
+        _ => return 0.00,
+    };
+    match current.form {
+        Form::ThirdPerson if !third_person_singular => 0.4,
+        Form::Base | Form::Present if third_person_singular => 0.4,
+        _ => 0.00,
+    }
+}
+
+/// Nouns are singular unless they're in their plural form.
+pub fn noun_number(gene: GeneType) -> Number {
+    match gene.form {
+        Form::Plural => Number::Plural,
+        _ => Number::Singular,
+    }
+}
+
+/// Pronouns before a verb are its subject and pronouns after a verb or preposition are its
+/// object, so "him eats" and "with she" are penalised.
+fn case_penalty(previous: GeneType, current: GeneType) -> f32 {
+    match (previous.word_type, current.word_type) {
+        (WordType::Pronoun, WordType::Verb) => match pronoun_case(previous) {
+            Case::Object => 0.5,
+            Case::Possessive => 0.3,
+            Case::Subject | Case::Both => 0.00,
+        },
+        (WordType::Verb | WordType::Preposition, WordType::Pronoun) => {
+            match pronoun_case(current) {
+                Case::Subject => 0.4,
+                Case::Object | Case::Possessive | Case::Both => 0.00,
+            }
+        }
+        _ => 0.00,
+    }
+}
+
+fn pronoun_case(gene: GeneType) -> Case {
+    PRONOUNS.words[gene.index as usize].attributes.case
+}
+
+/// Scores the grammar of a sentence, 1.00 means nothing was penalised and it never drops below 0.00.
+pub fn score(genes: &[GeneType], request: &Request) -> f32 {
+    let inverted = request.mood == Mood::Interrogative
+        && genes.first().is_some_and(|&g| mood::is_auxiliary(g));
+    let misplaced_interjections = genes
+        .iter()
+        .skip(1)
+        .filter(|g| g.word_type == WordType::Interjection)
+        .count() as f32;
+    let mut penalties: f32 = genes
+        .windows(2)
+        .map(|pair| penalty(pair[0], pair[1], inverted))
+        .sum::<f32>()
+        + misplaced_interjections * MISPLACED_INTERJECTION_PENALTY
+        + tense::penalty(genes, request.tense, request.aspect, inverted)
+        + request.mood.penalty(genes)
+        + request.shape.penalty(genes);
+    if inverted {
+        penalties += inversion_penalty(genes);
+    }
+    (1.0 - penalties).max(0.0)
+}
@@ -0,0 +1,101 @@
+use crate::{blocklist, chromosome::Chromosome, construct_sentence, request::Request};
+
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub sentence: String,
+    pub chromosome: Chromosome,
+}
+
+impl Entry {
+    pub fn fitness(&self) -> f32 {
+        self.chromosome.fitness.unwrap_or(0.00)
+    }
+}
+
+/// Keeps the best unique sentences seen across every generation of a run, leaving out any
+/// that match the blocklist.
+#[derive(Debug)]
+pub struct HallOfFame {
+    capacity: usize,
+    entries: Vec<Entry>,
+}
+
+impl HallOfFame {
+    pub fn new(capacity: usize) -> Self {
+        HallOfFame {
+            capacity,
+            entries: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Adds the population's best chromosomes that aren't on the blocklist, expects the
+    /// population to be sorted descending.
+    pub fn update(&mut self, population: &[Chromosome], request: &Request) {
+        for chromosome in population {
+            let fitness = chromosome.fitness.unwrap_or(0.00);
+            if self.entries.len() == self.capacity && fitness <= self.lowest_fitness() {
+                break;
+            }
+            if blocklist::is_blocked(&chromosome.genes, request) {
+                continue;
+            }
+            self.insert(chromosome, request);
+        }
+    }
+
+    /// Inserts the chromosome in fitness order, replacing an entry with the same sentence only
+    /// if it is fitter.
+    fn insert(&mut self, chromosome: &Chromosome, request: &Request) {
+        let sentence = construct_sentence(chromosome, request);
+        let fitness = chromosome.fitness.unwrap_or(0.00);
+        if let Some(same) = self.entries.iter().position(|e| e.sentence == sentence) {
+            if self.entries[same].fitness() >= fitness {
+                return;
+            }
+            self.entries.remove(same);
+        }
+        let position = self
+            .entries
+            .iter()
+            .position(|e| e.fitness() < fitness)
+            .unwrap_or(self.entries.len());
+        self.entries.insert(
+            position,
+            Entry {
+                sentence,
+                chromosome: chromosome.clone(),
+            },
+        );
+        self.entries.truncate(self.capacity);
+    }
+
+    fn lowest_fitness(&self) -> f32 {
+        self.entries.last().map(Entry::fitness).unwrap_or(f32::MIN)
+    }
+
+    /// The kept sentences, best first.
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_sentence_keeps_the_fitter_entry() {
+        let request = Request::default();
+        let genes = Chromosome::random(&request).genes;
+        let with_fitness = |fitness| Chromosome {
+            fitness: Some(fitness),
+            ..Chromosome::from_genes(genes.clone())
+        };
+        let mut hall_of_fame = HallOfFame::new(2);
+        hall_of_fame.update(&[with_fitness(0.5)], &request);
+        hall_of_fame.update(&[with_fitness(0.9)], &request);
+        hall_of_fame.update(&[with_fitness(0.7)], &request);
+        assert_eq!(hall_of_fame.entries().len(), 1);
+        assert_eq!(hall_of_fame.entries()[0].fitness(), 0.9);
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{
+    blocklist::BlocklistConfig,
+    chromosome::Chromosome,
+    clause::Shape,
+    crossover::CrossoverOperator,
+    local_search::{AnnealingConfig, HillClimbingConfig},
+    mood::Mood,
+    rates::{
+        AdjectiveTypeRates, AdverbTypeRates, ConjunctionTypeRates, DeterminerTypeRates,
+        InterjectionTypeRates, NounRates, NumeralTypeRates, PositionRates, PrepositionTypeRates,
+        PronounTypeRates, VerbTypeRates, WordTypeRates, available, thresholds,
+    },
+    render::RenderingConfig,
+    request::Request,
+    structures::{
+        WORD_COUNT_STRUCTURE_EIGHT, WORD_COUNT_STRUCTURE_FIVE, WORD_COUNT_STRUCTURE_FOUR,
+        WORD_COUNT_STRUCTURE_SEVEN, WORD_COUNT_STRUCTURE_SIX, WORD_COUNT_STRUCTURE_THREE,
+    },
+    tense::{Aspect, Tense},
+    words::collection,
+};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::{borrow::Cow, collections::HashMap, fs, path::PathBuf, process, sync::OnceLock};
+
+pub mod blocklist;
+pub mod chromosome;
+pub mod clause;
+pub mod coherence;
+pub mod crossover;
+pub mod fit;
+pub mod ga;
+pub mod grammar;
+pub mod hall_of_fame;
+pub mod local_search;
+pub mod mood;
+pub mod morphology;
+pub mod nsga;
+pub mod paragraph;
+pub mod rates;
+pub mod render;
+pub mod request;
+pub mod required;
+pub mod rng;
+pub mod search;
+pub mod semantic;
+pub mod strategy;
+pub mod structures;
+pub mod template;
+pub mod tense;
+pub mod words;
+
+// Past these the weights are raised to a power where they overflow or lose all but the
+// commonest word
+const RARITY_RANGE: std::ops::RangeInclusive<f32> = -2.0..=2.0;
+
+/// Where `CONFIG` is read from, defaults to `config.toml` if nothing is set before first use.
+pub static CONFIG_PATH: OnceLock<PathBuf> = OnceLock::new();
+/// A directory to read the word lists from instead of the built in ones, set before first use.
+pub static LEXICON_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+lazy_static! {
+    pub static ref CONFIG: Config = {
+        let path = CONFIG_PATH.get_or_init(|| PathBuf::from("config.toml"));
+        let config_string = match fs::read_to_string(path) {
+            Ok(cs) => cs,
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        };
+        match toml::from_str::<Config>(&config_string) {
+            Ok(c) => {
+                if let Err(e) = c.validate() {
+                    eprintln!("{e}");
+                    process::exit(1);
+                }
+                c
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                process::exit(1);
+            }
+        }
+    };
+    pub static ref WORD_THRESHOLDS: Vec<(f32, WordType)> =
+        word_type_thresholds(&CONFIG.word_type_rates);
+    /// Thresholds for the first word, when the config gives start rates.
+    pub static ref START_THRESHOLDS: Option<Vec<(f32, WordType)>> =
+        CONFIG.position_rates.start.as_ref().map(word_type_thresholds);
+    /// Thresholds for the word after each word type the config gives rates for.
+    pub static ref AFTER_THRESHOLDS: HashMap<WordType, Vec<(f32, WordType)>> = CONFIG
+        .position_rates
+        .after
+        .iter()
+        .map(|(&word_type, rates)| (word_type, word_type_thresholds(rates)))
+        .collect();
+}
+
+fn word_type_thresholds(rates: &WordTypeRates) -> Vec<(f32, WordType)> {
+    let rates = available(
+        vec![
+            (rates.adverb, WordType::Adverb),
+            (rates.noun, WordType::Noun),
+            (rates.pronoun, WordType::Pronoun),
+            (rates.verb, WordType::Verb),
+            (rates.preposition, WordType::Preposition),
+            (rates.conjunction, WordType::Conjunction),
+            (rates.determiner, WordType::Determiner),
+            (rates.adjective, WordType::Adjective),
+            (rates.interjection, WordType::Interjection),
+            (rates.numeral, WordType::Numeral),
+        ],
+        |word_type| collection(word_type).size() > 0,
+    );
+    if rates.is_empty() {
+        eprintln!("No word type with a rate above 0 has any words");
+        process::exit(1);
+    }
+    thresholds(rates)
+}
+
+/// Picks one of the structures for the configured word count and the mood at random, or
+/// none if structure fitness is off. Sentences with more than one clause are built from clause
+/// structures instead.
+pub fn select_structure(mood: Mood, shape: Shape) -> Cow<'static, [WordType]> {
+    if !CONFIG.use_structure_fitness {
+        return Cow::Borrowed(&[]);
+    }
+    if shape != Shape::Simple {
+        return Cow::Owned(shape.structure(CONFIG.word_count));
+    }
+    Cow::Borrowed(select_simple_structure(mood))
+}
+
+fn select_simple_structure(mood: Mood) -> &'static [WordType] {
+    let structures = mood.structures();
+    if !structures.is_empty() {
+        let matching: Vec<&'static [WordType]> = structures
+            .iter()
+            .copied()
+            .filter(|s| s.len() == CONFIG.word_count)
+            .collect();
+        if matching.is_empty() {
+            return structures[structures.len() - 1];
+        }
+        return matching[rng::random_range(0..matching.len())];
+    }
+    match CONFIG.word_count {
+        3 => &WORD_COUNT_STRUCTURE_THREE[rng::random_range(0..=1)],
+        4 => &WORD_COUNT_STRUCTURE_FOUR,
+        5 => &WORD_COUNT_STRUCTURE_FIVE[rng::random_range(0..=3)],
+        6 => &WORD_COUNT_STRUCTURE_SIX,
+        7 => &WORD_COUNT_STRUCTURE_SEVEN,
+        _ => &WORD_COUNT_STRUCTURE_EIGHT,
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct Config {
+    pub generations: i32,
+    pub population_count: i32,
+    pub crossover_probability: f32,
+    #[serde(default)]
+    pub crossover_operator: CrossoverOperator,
+    pub mutation_probability: f32,
+    pub use_structure_fitness: bool,
+    pub structure_weight: f32,
+    pub use_grammar_fitness: bool,
+    pub grammar_weight: f32,
+    #[serde(default)]
+    pub use_semantic_fitness: bool,
+    #[serde(default = "default_semantic_weight")]
+    pub semantic_weight: f32,
+    #[serde(default)]
+    pub multi_objective: bool,
+    #[serde(default)]
+    pub strict_rates: bool,
+    #[serde(default)]
+    pub rarity: f32,
+    #[serde(default = "default_paragraph_sentences")]
+    pub paragraph_sentences: usize,
+    #[serde(default = "default_coherence_weight")]
+    pub coherence_weight: f32,
+    #[serde(default)]
+    pub required_words: Vec<String>,
+    #[serde(default)]
+    pub ordered_required_words: bool,
+    #[serde(default = "default_required_words_weight")]
+    pub required_words_weight: f32,
+    pub template: Option<String>,
+    #[serde(default)]
+    pub blocklist: BlocklistConfig,
+    pub word_count: usize,
+    #[serde(default = "default_hall_of_fame_size")]
+    pub hall_of_fame_size: usize,
+    #[serde(default = "default_beam_width")]
+    pub beam_width: usize,
+    #[serde(default = "default_beam_candidates")]
+    pub beam_candidates: usize,
+    #[serde(default)]
+    pub annealing: AnnealingConfig,
+    #[serde(default)]
+    pub hill_climbing: HillClimbingConfig,
+    pub tense: Option<Tense>,
+    pub aspect: Option<Aspect>,
+    #[serde(default)]
+    pub mood: Mood,
+    #[serde(default)]
+    pub shape: Shape,
+    #[serde(default)]
+    pub rendering: RenderingConfig,
+
+    pub word_type_rates: WordTypeRates,
+    #[serde(default)]
+    pub position_rates: PositionRates,
+    pub noun_rates: NounRates,
+    pub pronoun_type_rates: PronounTypeRates,
+    pub verb_type_rates: VerbTypeRates,
+    pub adverb_type_rates: AdverbTypeRates,
+    pub adjective_type_rates: AdjectiveTypeRates,
+    pub preposition_type_rates: PrepositionTypeRates,
+    pub determiner_type_rates: DeterminerTypeRates,
+    pub conjunction_type_rates: ConjunctionTypeRates,
+    pub interjection_type_rates: InterjectionTypeRates,
+    pub numeral_type_rates: NumeralTypeRates,
+}
+
+impl Config {
+    /// Checks the settings that would otherwise panic part way through a run.
+    fn validate(&self) -> Result<(), String> {
+        if self.population_count < 2 {
+            return Err(format!(
+                "population_count has to be at least 2, the GA breeds the two best chromosomes. Got {}",
+                self.population_count
+            ));
+        }
+        if self.hall_of_fame_size == 0 {
+            return Err("hall_of_fame_size has to be at least 1".to_string());
+        }
+        if !RARITY_RANGE.contains(&self.rarity) {
+            return Err(format!(
+                "rarity has to be between {} and {}. Got {}",
+                RARITY_RANGE.start(),
+                RARITY_RANGE.end(),
+                self.rarity
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn default_hall_of_fame_size() -> usize {
+    1
+}
+fn default_semantic_weight() -> f32 {
+    0.3
+}
+fn default_paragraph_sentences() -> usize {
+    1
+}
+fn default_coherence_weight() -> f32 {
+    0.5
+}
+fn default_required_words_weight() -> f32 {
+    1.0
+}
+fn default_beam_width() -> usize {
+    50
+}
+fn default_beam_candidates() -> usize {
+    5
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum WordType {
+    Noun,
+    Pronoun,
+    Verb,
+    Adverb,
+    Adjective,
+    Conjunction,
+    Preposition,
+    Determiner,
+    Interjection,
+    Numeral,
+}
+
+impl WordType {
+    pub const ALL: [WordType; 10] = [
+        WordType::Noun,
+        WordType::Pronoun,
+        WordType::Verb,
+        WordType::Adverb,
+        WordType::Adjective,
+        WordType::Conjunction,
+        WordType::Preposition,
+        WordType::Determiner,
+        WordType::Interjection,
+        WordType::Numeral,
+    ];
+}
+
+pub fn construct_sentence(chromosome: &Chromosome, request: &Request) -> String {
+    render::render(&chromosome.genes, request)
+}
@@ -0,0 +1,137 @@
+use std::slice;
+
+use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
+use serde::Deserialize;
+
+use crate::{
+    CONFIG,
+    chromosome::{Chromosome, select_word_at},
+    hall_of_fame::HallOfFame,
+    request::Request,
+    rng,
+};
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum Schedule {
+    #[default]
+    Geometric,
+    Linear,
+    Logarithmic,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(default)]
+pub struct AnnealingConfig {
+    pub iterations: usize,
+    pub initial_temperature: f32,
+    pub final_temperature: f32,
+    pub schedule: Schedule,
+}
+
+impl Default for AnnealingConfig {
+    fn default() -> Self {
+        AnnealingConfig {
+            iterations: 20000,
+            initial_temperature: 1.0,
+            final_temperature: 0.001,
+            schedule: Schedule::Geometric,
+        }
+    }
+}
+
+impl AnnealingConfig {
+    /// Temperature at the given iteration, going from the initial to the final temperature.
+    pub fn temperature(&self, iteration: usize) -> f32 {
+        let progress = iteration as f32 / self.iterations.max(1) as f32;
+        let (initial, last) = (self.initial_temperature, self.final_temperature);
+        match self.schedule {
+            Schedule::Geometric => initial * (last / initial).powf(progress),
+            Schedule::Linear => initial - (initial - last) * progress,
+            Schedule::Logarithmic => (initial / (1.0 + (1.0 + iteration as f32).ln())).max(last),
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+#[serde(default)]
+pub struct HillClimbingConfig {
+    pub max_steps: usize,
+    pub neighbours_per_word: usize,
+}
+
+impl Default for HillClimbingConfig {
+    fn default() -> Self {
+        HillClimbingConfig {
+            max_steps: 1000,
+            neighbours_per_word: 10,
+        }
+    }
+}
+
+/// A copy of the chromosome with one word replaced, the same change a mutation makes.
+fn neighbour(request: &Request, chromosome: &Chromosome) -> Chromosome {
+    let mut neighbour = Chromosome::from_genes(chromosome.genes.clone());
+    if !neighbour.genes.is_empty() {
+        let position = rng::random_range(0..neighbour.genes.len());
+        neighbour.genes[position] = select_word_at(request, &chromosome.genes[..position]);
+    }
+    neighbour.evaluate(request);
+    neighbour
+}
+
+fn fitness(chromosome: &Chromosome) -> f32 {
+    chromosome.fitness.unwrap_or(0.00)
+}
+
+/// Simulated annealing from a random chromosome.
+pub fn annealing(request: &Request, hall_of_fame: &mut HallOfFame) {
+    let mut start = Chromosome::random(request);
+    start.evaluate(request);
+    anneal_from(request, start, hall_of_fame);
+}
+
+/// Simulated annealing from the given chromosome, always accepting better neighbours and
+/// accepting worse ones with a probability that shrinks as the temperature falls.
+pub fn anneal_from(request: &Request, start: Chromosome, hall_of_fame: &mut HallOfFame) {
+    let settings = CONFIG.annealing;
+    let mut current = start;
+    hall_of_fame.update(slice::from_ref(&current), request);
+    for iteration in 0..settings.iterations {
+        let candidate = neighbour(request, &current);
+        let delta = fitness(&candidate) - fitness(&current);
+        let temperature = settings.temperature(iteration);
+        if delta >= 0.0 || rng::random_range(0.00..1.00) < (delta / temperature).exp() {
+            current = candidate;
+            hall_of_fame.update(slice::from_ref(&current), request);
+        }
+    }
+}
+
+/// Steepest-ascent hill climbing, moving to the best neighbour until none improve.
+pub fn hill_climbing(request: &Request, hall_of_fame: &mut HallOfFame) {
+    let settings = CONFIG.hill_climbing;
+    let mut current = Chromosome::random(request);
+    current.evaluate(request);
+    hall_of_fame.update(slice::from_ref(&current), request);
+    for _ in 0..settings.max_steps {
+        let mut neighbours: Vec<Chromosome> = Vec::new();
+        for position in 0..current.genes.len() {
+            for _ in 0..settings.neighbours_per_word {
+                let mut neighbour = Chromosome::from_genes(current.genes.clone());
+                neighbour.genes[position] = select_word_at(request, &current.genes[..position]);
+                neighbours.push(neighbour);
+            }
+        }
+        neighbours.par_iter_mut().for_each(|c| c.evaluate(request));
+        let best = neighbours
+            .into_iter()
+            .max_by(|a, b| fitness(a).total_cmp(&fitness(b)));
+        match best {
+            Some(best) if fitness(&best) > fitness(&current) => {
+                current = best;
+                hall_of_fame.update(slice::from_ref(&current), request);
+            }
+            _ => break,
+        }
+    }
+}
@@ -1,175 +1,136 @@
-use crate::{
-    chromosome::Chromosome,
-    rates::{
-        AdjectiveTypeRates, AdverbTypeRates, ConjunctionTypeRates, DeterminerTypeRates, NounRates,
-        PrepositionTypeRates, VerbTypeRates, WordTypeRates, check_rates,
-    },
-    structures::{
-        WORD_COUNT_STRUCTURE_EIGHT, WORD_COUNT_STRUCTURE_FIVE, WORD_COUNT_STRUCTURE_FOUR,
-        WORD_COUNT_STRUCTURE_SEVEN, WORD_COUNT_STRUCTURE_SIX, WORD_COUNT_STRUCTURE_THREE,
-    },
-};
-use genetica::{
-    crossover::dynamic_length_single_point_crossover,
-    individual::Individual,
-    population::{generate_population, sort_population_descending},
-};
-use lazy_static::lazy_static;
-use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
-use serde::Deserialize;
 use std::{fs, process};
 
-mod chromosome;
-mod rates;
-mod structures;
-mod words;
-
-lazy_static! {
-    pub static ref CONFIG: Config = {
-        let config_string = match fs::read_to_string("config.toml") {
-            Ok(cs) => cs,
-            Err(e) => {
-                eprintln!("{e}");
-                process::exit(1);
-            }
-        };
-        match toml::from_str(&config_string) {
-            Ok(c) => c,
-            Err(e) => {
-                eprintln!("{e}");
-                process::exit(1);
-            }
-        }
-    };
-    pub static ref STRUCTURE: &'static [WordType] = {
-        if CONFIG.use_structure_fitness {
-            let structure: &[WordType] = match CONFIG.word_count {
-                3 => &WORD_COUNT_STRUCTURE_THREE[rand::random_range(0..=1)],
-                4 => &WORD_COUNT_STRUCTURE_FOUR,
-                5 => &WORD_COUNT_STRUCTURE_FIVE[rand::random_range(0..=3)],
-                6 => &WORD_COUNT_STRUCTURE_SIX,
-                7 => &WORD_COUNT_STRUCTURE_SEVEN,
-                _ => &WORD_COUNT_STRUCTURE_EIGHT,
-            };
-            structure
-        } else {
-            &[]
-        }
-    };
-    pub static ref WORD_THRESHOLDS: [(f32, WordType); 7] = {
-        let rates = CONFIG.word_type_rates;
-        #[rustfmt::skip]
-        let thresholds: [(f32, WordType); 7] = [
-        (rates.adverb, WordType::Adverb),
-        (rates.adverb + rates.noun, WordType::Noun),
-        (rates.adverb + rates.noun + rates.verb, WordType::Verb),
-        (rates.adverb + rates.noun + rates.verb + rates.preposition, WordType::Preposition),
-        (rates.adverb + rates.noun + rates.verb + rates.preposition + rates.conjunction, WordType::Conjunction),
-        (rates.adverb + rates.noun + rates.verb + rates.preposition + rates.conjunction + rates.determiner, WordType::Determiner),
-        (rates.adverb + rates.noun + rates.verb + rates.preposition + rates.conjunction + rates.determiner + rates.adjective, WordType::Adjective)
-        ];
-        thresholds
-    };
-}
-
-#[derive(Deserialize, Debug)]
-pub struct Config {
-    pub generations: i32,
-    pub population_count: i32,
-    pub crossover_probability: f32,
-    pub mutation_probability: f32,
-    pub use_structure_fitness: bool,
-    pub structure_weight: f32,
-    pub use_grammar_fitness: bool,
-    pub grammar_weight: f32,
-    pub word_count: usize,
+use sengen::{
+    CONFIG,
+    chromosome::Chromosome,
+    construct_sentence, fit, nsga, paragraph,
+    rates::check_rates,
+    request::Request,
+    strategy::{self, Strategy},
+};
 
-    pub word_type_rates: WordTypeRates,
-    pub noun_rates: NounRates,
-    pub verb_type_rates: VerbTypeRates,
-    pub adverb_type_rates: AdverbTypeRates,
-    pub adjective_type_rates: AdjectiveTypeRates,
-    pub preposition_type_rates: PrepositionTypeRates,
-    pub determiner_type_rates: DeterminerTypeRates,
-    pub conjunction_type_rates: ConjunctionTypeRates,
-}
+use crate::cli::Args;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub enum WordType {
-    Noun,
-    Verb,
-    Adverb,
-    Adjective,
-    Conjunction,
-    Preposition,
-    Determiner,
-}
+mod cli;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     check_rates("Word type", &CONFIG.word_type_rates);
     check_rates("Noun type", &CONFIG.noun_rates.type_rates);
     check_rates("Noun tangibility", &CONFIG.noun_rates.tangibility_rates);
     check_rates("Noun countability", &CONFIG.noun_rates.countability_rates);
+    check_rates("Pronoun type", &CONFIG.pronoun_type_rates);
     check_rates("Verb type", &CONFIG.verb_type_rates);
     check_rates("Adverb type", &CONFIG.adverb_type_rates);
     check_rates("Adjective type", &CONFIG.adjective_type_rates);
     check_rates("Preposition type", &CONFIG.preposition_type_rates);
     check_rates("Determiner type", &CONFIG.determiner_type_rates);
     check_rates("Conjunction type", &CONFIG.conjunction_type_rates);
+    check_rates("Interjection type", &CONFIG.interjection_type_rates);
+    check_rates("Numeral type", &CONFIG.numeral_type_rates);
+    if let Some(rates) = &CONFIG.position_rates.start {
+        check_rates("Start word type", rates);
+    }
+    for (word_type, rates) in &CONFIG.position_rates.after {
+        check_rates(&format!("{word_type:?} next word type"), rates);
+    }
 
-    let mut population: Vec<Chromosome> = generate_population(CONFIG.population_count);
-
-    for _ in 0..CONFIG.generations {
-        let parent1 = &population[0];
-        let parent2 = &population[1];
-        let (mut child1, mut child2) =
-            dynamic_length_single_point_crossover(parent1, parent2, CONFIG.crossover_probability);
-        child1.mutate_genes();
-        child2.mutate_genes();
+    let args = Args::parse();
+    if let Some(corpus) = &args.fit_rates {
+        let corpus = fs::read_to_string(corpus).unwrap_or_else(|e| {
+            eprintln!("Error reading {}: {e}", corpus.display());
+            process::exit(1);
+        });
+        let (fragment, tagged, untagged) = fit::fit_rates(&corpus);
+        eprintln!("Tagged {tagged} words, {untagged} weren't in the lexicon");
+        print!("{fragment}");
+        return Ok(());
+    }
+    let request = Request::new(
+        args.tense.or(CONFIG.tense),
+        args.aspect.or(CONFIG.aspect),
+        args.mood.unwrap_or(CONFIG.mood),
+        args.shape.unwrap_or(CONFIG.shape),
+    )
+    .with_template(args.template.as_deref().or(CONFIG.template.as_deref()))
+    .with_required_words(
+        args.required_words
+            .as_ref()
+            .unwrap_or(&CONFIG.required_words),
+        args.ordered || CONFIG.ordered_required_words,
+    );
+    if args.compare {
+        strategy::compare(&request);
+        return Ok(());
+    }
 
-        let mut new_population: Vec<Chromosome> = generate_population(CONFIG.population_count - 4);
+    if CONFIG.multi_objective && args.strategy == Strategy::Ga {
+        let front = nsga::run(&request);
+        if front.is_empty() {
+            exit_blocked();
+        }
+        print_pareto_front(&front, &request);
+        return Ok(());
+    }
 
-        new_population.push(child1);
-        new_population.push(child2);
-        new_population.push(parent1.clone());
-        new_population.push(parent2.clone());
-        population = new_population;
-        population
-            .par_iter_mut()
-            .for_each(|c| c.calculate_fitness());
-        sort_population_descending(&mut population);
+    let sentences = args.paragraph.unwrap_or(CONFIG.paragraph_sentences);
+    if sentences > 1 {
+        let paragraph = paragraph::run(args.strategy, sentences, &request);
+        if paragraph.len() < sentences {
+            exit_blocked();
+        }
+        for entry in &paragraph {
+            println!("Fitness: {}", entry.fitness());
+        }
+        println!("{}", paragraph::join(&paragraph));
+        return Ok(());
     }
 
-    sort_population_descending(&mut population);
-    let best = &population[0];
-    let sentence = construct_sentence(best);
-    println!("Fitness: {}", best.fitness.unwrap_or(0.00));
-    println!("{sentence}");
+    let hall_of_fame = strategy::run(args.strategy, &request);
+    if hall_of_fame.entries().is_empty() {
+        exit_blocked();
+    }
+    for entry in hall_of_fame.entries() {
+        println!("Fitness: {}", entry.fitness());
+        println!("{}", entry.sentence);
+        if args.explain {
+            explain(&entry.chromosome, &request);
+        }
+    }
 
     Ok(())
 }
 
-fn construct_sentence(chromosome: &Chromosome) -> String {
-    let mut words = chromosome
-        .genes
-        .iter()
-        .map(|g| g.word.get_word().to_string())
-        .collect::<Vec<String>>();
-    words[0] = capitalize(&words[0]);
-    let mut sentence = words.join(" ");
-    sentence.push_str(".");
-    sentence
+/// Prints each fitness component unweighted with its weight.
+fn explain(chromosome: &Chromosome, request: &Request) {
+    let names = Chromosome::enabled_objectives(request);
+    for ((name, weight), value) in names.iter().zip(&chromosome.objectives) {
+        println!("  {name}: {value} (weight {weight})");
+    }
+}
+
+/// Sentences on the blocklist are never printed, so a run that found none without a match
+/// has nothing to show.
+fn exit_blocked() -> ! {
+    eprintln!("Every sentence generated matched the blocklist, try loosening it");
+    process::exit(1);
 }
 
-fn capitalize(word: &str) -> String {
-    let mut chars = word.chars();
-    match chars.next() {
-        None => String::new(),
-        Some(fl) => {
-            let mut capitalized_word = String::new();
-            capitalized_word.push(fl.to_ascii_uppercase());
-            capitalized_word.extend(chars);
-            capitalized_word
+fn print_pareto_front(front: &[Chromosome], request: &Request) {
+    let names = Chromosome::enabled_objectives(request);
+    let mut printed: Vec<String> = Vec::new();
+    for chromosome in front {
+        let sentence = construct_sentence(chromosome, request);
+        if printed.contains(&sentence) {
+            continue;
         }
+        let objectives = names
+            .iter()
+            .zip(&chromosome.objectives)
+            .map(|((name, _), value)| format!("{name}: {value}"))
+            .collect::<Vec<String>>()
+            .join(", ");
+        println!("{objectives}");
+        println!("{sentence}");
+        printed.push(sentence);
     }
 }
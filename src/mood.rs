@@ -0,0 +1,106 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::{
+    WordType,
+    chromosome::GeneType,
+    morphology::Form,
+    structures::{EXCLAMATIVE_STRUCTURES, IMPERATIVE_STRUCTURES, INTERROGATIVE_STRUCTURES},
+    words::{
+        ADJECTIVES, ADVERBS, AdjectiveType, AdverbType, DETERMINERS, DeterminerType, PRONOUNS,
+        PronounType, VERBS,
+    },
+};
+
+/// How much a sentence is penalised for not opening the way its mood needs.
+const MOOD_PENALTY: f32 = 0.5;
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Default)]
+pub enum Mood {
+    #[default]
+    Declarative,
+    Interrogative,
+    Imperative,
+    Exclamative,
+}
+
+impl FromStr for Mood {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "declarative" => Ok(Mood::Declarative),
+            "interrogative" => Ok(Mood::Interrogative),
+            "imperative" => Ok(Mood::Imperative),
+            "exclamative" => Ok(Mood::Exclamative),
+            _ => Err(format!(
+                "Unknown mood {s}, expected declarative, interrogative, imperative or exclamative"
+            )),
+        }
+    }
+}
+
+impl Mood {
+    pub fn terminator(self) -> char {
+        match self {
+            Mood::Declarative | Mood::Imperative => '.',
+            Mood::Interrogative => '?',
+            Mood::Exclamative => '!',
+        }
+    }
+
+    /// The structures sentences of this mood can have, declaratives use the word count
+    /// structures instead.
+    pub fn structures(self) -> &'static [&'static [WordType]] {
+        match self {
+            Mood::Declarative => &[],
+            Mood::Interrogative => INTERROGATIVE_STRUCTURES,
+            Mood::Imperative => IMPERATIVE_STRUCTURES,
+            Mood::Exclamative => EXCLAMATIVE_STRUCTURES,
+        }
+    }
+
+    /// Penalises a sentence that doesn't open the way its mood needs. Questions start with an
+    /// auxiliary or an interrogative word, imperatives with a base form verb and exclamations
+    /// with "what" or a degree adverb.
+    pub fn penalty(self, genes: &[GeneType]) -> f32 {
+        let Some(&first) = genes.first() else {
+            return 0.00;
+        };
+        let opens = match self {
+            Mood::Declarative => true,
+            Mood::Interrogative => is_auxiliary(first) || is_interrogative(first),
+            Mood::Imperative => {
+                first.word_type == WordType::Verb
+                    && first.form == Form::Base
+                    && !VERBS.words[first.index as usize].is_modal()
+            }
+            Mood::Exclamative => match first.word_type {
+                WordType::Determiner => {
+                    DETERMINERS.words[first.index as usize].r#type == DeterminerType::Interrogative
+                }
+                WordType::Adverb => {
+                    ADVERBS.words[first.index as usize].r#type == AdverbType::Degree
+                }
+                WordType::Interjection => true,
+                _ => false,
+            },
+        };
+        if opens { 0.00 } else { MOOD_PENALTY }
+    }
+}
+
+/// A verb that other verbs chain onto, which is what a question inverts ("Does the cat eat?").
+pub fn is_auxiliary(gene: GeneType) -> bool {
+    gene.word_type == WordType::Verb && VERBS.words[gene.index as usize].chains(gene.form)
+}
+
+fn is_interrogative(gene: GeneType) -> bool {
+    let index = gene.index as usize;
+    match gene.word_type {
+        WordType::Determiner => DETERMINERS.words[index].r#type == DeterminerType::Interrogative,
+        WordType::Pronoun => PRONOUNS.words[index].r#type == PronounType::Interrogative,
+        WordType::Adjective => ADJECTIVES.words[index].r#type == AdjectiveType::Interrogative,
+        _ => false,
+    }
+}
@@ -0,0 +1,333 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+/// An inflected form of a lemma, each part of speech only uses some of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+pub enum Form {
+    #[default]
+    Base,
+    /// A present tense form that differs from the base without being third person, only
+    /// "am" and "are" have one.
+    Present,
+    Plural,
+    ThirdPerson,
+    Past,
+    Gerund,
+    Participle,
+    Comparative,
+    Superlative,
+}
+
+impl Form {
+    pub const NOUN: [Form; 2] = [Form::Base, Form::Plural];
+    pub const VERB: [Form; 5] = [
+        Form::Base,
+        Form::ThirdPerson,
+        Form::Past,
+        Form::Gerund,
+        Form::Participle,
+    ];
+    pub const ADJECTIVE: [Form; 3] = [Form::Base, Form::Comparative, Form::Superlative];
+
+    /// A form on its own, for entries that are only ever written one way.
+    pub fn only(self) -> &'static [Form] {
+        match self {
+            Form::Base => &[Form::Base],
+            Form::Present => &[Form::Present],
+            Form::Plural => &[Form::Plural],
+            Form::ThirdPerson => &[Form::ThirdPerson],
+            Form::Past => &[Form::Past],
+            Form::Gerund => &[Form::Gerund],
+            Form::Participle => &[Form::Participle],
+            Form::Comparative => &[Form::Comparative],
+            Form::Superlative => &[Form::Superlative],
+        }
+    }
+}
+
+/// Inflects a lemma following the regular spelling rules, only the first word of a phrasal
+/// verb ("jotted down").
+pub fn inflect(lemma: &str, form: Form) -> Cow<'_, str> {
+    if let Some((head, particle)) = lemma.split_once(' ')
+        && Form::VERB.contains(&form)
+        && form != Form::Base
+    {
+        return Cow::Owned(format!("{} {particle}", inflect(head, form)));
+    }
+    match form {
+        Form::Base | Form::Present => Cow::Borrowed(lemma),
+        Form::Plural | Form::ThirdPerson => Cow::Owned(add_s(lemma)),
+        Form::Past | Form::Participle => Cow::Owned(add_ed(lemma)),
+        Form::Gerund => Cow::Owned(add_ing(lemma)),
+        Form::Comparative => Cow::Owned(compare(lemma, "er", "more")),
+        Form::Superlative => Cow::Owned(compare(lemma, "est", "most")),
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+fn ends_with_consonant_y(word: &str) -> bool {
+    let mut chars = word.chars().rev();
+    chars.next() == Some('y') && chars.next().is_some_and(|c| !is_vowel(c))
+}
+
+/// Groups of vowels, close enough to syllables for picking between "-er" and "more".
+fn syllables(word: &str) -> usize {
+    let word = word.strip_suffix('e').unwrap_or(word);
+    let mut count = 0;
+    let mut previous_vowel = false;
+    for c in word.chars() {
+        let vowel = is_vowel(c) || c == 'y';
+        if vowel && !previous_vowel {
+            count += 1;
+        }
+        previous_vowel = vowel;
+    }
+    count.max(1)
+}
+
+/// Prefixes that are never stressed, so the syllable after them is ("regret", "unzip").
+const UNSTRESSED_PREFIXES: [&str; 8] = ["ad", "be", "for", "oc", "per", "re", "sub", "un"];
+
+/// The last syllable when it can take the stress, the whole word when it has one syllable or
+/// what follows an unstressed prefix. Only the last part of a hyphenated word counts.
+fn stressed_final_syllable(word: &str) -> Option<&str> {
+    let word = word.rsplit('-').next().unwrap_or(word);
+    if syllables(word) == 1 {
+        return Some(word);
+    }
+    UNSTRESSED_PREFIXES
+        .iter()
+        .filter_map(|prefix| word.strip_prefix(prefix))
+        .find(|rest| syllables(rest) == 1)
+}
+
+/// Words ending in a stressed consonant-vowel-consonant double the last letter ("stop",
+/// "stopped", "occur", "occurred"). The "u" after a "q" counts as a consonant ("quiz").
+fn doubles_final_consonant(word: &str) -> bool {
+    let Some(syllable) = stressed_final_syllable(word) else {
+        return false;
+    };
+    let chars: Vec<char> = syllable.chars().collect();
+    match chars[..] {
+        [.., 'q', 'u', b, c] => is_vowel(b) && !is_vowel(c) && !matches!(c, 'w' | 'x' | 'y'),
+        [.., a, b, c] => {
+            !is_vowel(a) && is_vowel(b) && !is_vowel(c) && !matches!(c, 'w' | 'x' | 'y')
+        }
+        _ => false,
+    }
+}
+
+/// Words ending in a vowel and "c" take a "k" before a suffix ("panic", "panicked").
+fn ends_with_vowel_c(word: &str) -> bool {
+    let mut chars = word.chars().rev();
+    chars.next() == Some('c') && chars.next().is_some_and(is_vowel)
+}
+
+fn add_s(word: &str) -> String {
+    if ["s", "x", "z", "ch", "sh"]
+        .iter()
+        .any(|s| word.ends_with(s))
+    {
+        if word.ends_with('z') && doubles_final_consonant(word) {
+            format!("{word}zes")
+        } else {
+            format!("{word}es")
+        }
+    } else if ends_with_consonant_y(word) {
+        format!("{}ies", &word[..word.len() - 1])
+    } else if word.ends_with('o') && !word.ends_with("oo") {
+        format!("{word}es")
+    } else {
+        format!("{word}s")
+    }
+}
+
+fn add_ed(word: &str) -> String {
+    if word.ends_with('e') {
+        format!("{word}d")
+    } else if ends_with_consonant_y(word) {
+        format!("{}ied", &word[..word.len() - 1])
+    } else if ends_with_vowel_c(word) {
+        format!("{word}ked")
+    } else if doubles_final_consonant(word) {
+        format!("{word}{}ed", &word[word.len() - 1..])
+    } else {
+        format!("{word}ed")
+    }
+}
+
+fn add_ing(word: &str) -> String {
+    if let Some(stem) = word.strip_suffix("ie") {
+        format!("{stem}ying")
+    } else if word.ends_with('e') && !["ee", "ye", "oe"].iter().any(|s| word.ends_with(s)) {
+        format!("{}ing", &word[..word.len() - 1])
+    } else if ends_with_vowel_c(word) {
+        format!("{word}king")
+    } else if doubles_final_consonant(word) {
+        format!("{word}{}ing", &word[word.len() - 1..])
+    } else {
+        format!("{word}ing")
+    }
+}
+
+/// One syllable adjectives and two syllable ones ending in "y" take a suffix, longer ones
+/// take "more" or "most".
+fn compare(word: &str, suffix: &str, adverb: &str) -> String {
+    match syllables(word) {
+        1 if word.ends_with('e') => format!("{word}{}", &suffix[1..]),
+        1 if doubles_final_consonant(word) => {
+            format!("{word}{}{suffix}", &word[word.len() - 1..])
+        }
+        1 => format!("{word}{suffix}"),
+        2 if ends_with_consonant_y(word) => format!("{}i{suffix}", &word[..word.len() - 1]),
+        _ => format!("{adverb} {word}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::words::{VERBS, VerbType, Word};
+
+    #[test]
+    fn add_s_follows_the_spelling_rules() {
+        for (word, expected) in [
+            ("cat", "cats"),
+            ("watch", "watches"),
+            ("box", "boxes"),
+            ("buzz", "buzzes"),
+            ("quiz", "quizzes"),
+            ("carry", "carries"),
+            ("play", "plays"),
+            ("echo", "echoes"),
+            ("zoo", "zoos"),
+        ] {
+            assert_eq!(add_s(word), expected);
+        }
+    }
+
+    #[test]
+    fn add_ed_follows_the_spelling_rules() {
+        for (word, expected) in [
+            ("walk", "walked"),
+            ("bake", "baked"),
+            ("carry", "carried"),
+            ("play", "played"),
+            ("stop", "stopped"),
+            ("fix", "fixed"),
+            ("visit", "visited"),
+            ("open", "opened"),
+            ("occur", "occurred"),
+            ("admit", "admitted"),
+            ("regret", "regretted"),
+            ("unzip", "unzipped"),
+            ("uncover", "uncovered"),
+            ("name-tag", "name-tagged"),
+            ("quiz", "quizzed"),
+            ("quip", "quipped"),
+            ("panic", "panicked"),
+        ] {
+            assert_eq!(add_ed(word), expected);
+        }
+    }
+
+    #[test]
+    fn add_ing_follows_the_spelling_rules() {
+        for (word, expected) in [
+            ("walk", "walking"),
+            ("bake", "baking"),
+            ("see", "seeing"),
+            ("lie", "lying"),
+            ("run", "running"),
+            ("begin", "beginning"),
+            ("forget", "forgetting"),
+            ("happen", "happening"),
+            ("quit", "quitting"),
+            ("panic", "panicking"),
+        ] {
+            assert_eq!(add_ing(word), expected);
+        }
+    }
+
+    #[test]
+    fn compare_follows_the_spelling_rules() {
+        for (word, expected) in [
+            ("small", "smaller"),
+            ("nice", "nicer"),
+            ("hot", "hotter"),
+            ("tasty", "tastier"),
+            ("ancient", "more ancient"),
+            ("courageous", "more courageous"),
+        ] {
+            assert_eq!(compare(word, "er", "more"), expected);
+        }
+        assert_eq!(compare("red", "est", "most"), "reddest");
+    }
+
+    #[test]
+    fn phrasal_verbs_inflect_their_first_word() {
+        assert_eq!(inflect("jot down", Form::ThirdPerson), "jots down");
+        assert_eq!(inflect("jot down", Form::Past), "jotted down");
+        assert_eq!(inflect("jot down", Form::Gerund), "jotting down");
+        assert_eq!(inflect("jot down", Form::Base), "jot down");
+    }
+
+    #[test]
+    fn irregular_entries_give_their_past_and_participle() {
+        for verb in VERBS
+            .words
+            .iter()
+            .filter(|v| v.r#type == VerbType::Irregular)
+        {
+            let past = verb.attributes.past.as_deref();
+            let participle = verb.attributes.participle.as_deref();
+            assert!(past.is_some(), "{} has no past", verb.word);
+            assert!(participle.is_some(), "{} has no participle", verb.word);
+            assert_eq!(verb.inflect(Form::Past).as_ref(), past.unwrap());
+            assert_eq!(verb.inflect(Form::Participle).as_ref(), participle.unwrap());
+            assert!(
+                past != Some(&inflect(&verb.word, Form::Past))
+                    || participle != Some(&inflect(&verb.word, Form::Participle)),
+                "{} inflects regularly, it isn't irregular",
+                verb.word
+            );
+        }
+    }
+
+    #[test]
+    fn every_entry_of_an_irregular_verb_shares_its_forms() {
+        for irregular in VERBS
+            .words
+            .iter()
+            .filter(|v| v.r#type == VerbType::Irregular)
+        {
+            for verb in VERBS
+                .words
+                .iter()
+                .filter(|v| v.word == irregular.word && v.attributes.form.is_none())
+            {
+                for form in [Form::Past, Form::Participle] {
+                    assert_eq!(
+                        verb.inflect(form),
+                        irregular.inflect(form),
+                        "{:?} {} in {form:?}",
+                        verb.r#type,
+                        verb.word
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn swim_is_irregular() {
+        let swim = VERBS.words.iter().find(|v| v.word == "swim").unwrap();
+        assert_eq!(swim.inflect(Form::Past), "swam");
+        assert_eq!(swim.inflect(Form::Participle), "swum");
+        assert_eq!(swim.inflect(Form::Gerund), "swimming");
+    }
+}
@@ -0,0 +1,189 @@
+use std::cmp::Ordering;
+
+use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
+
+use crate::{
+    CONFIG, blocklist,
+    chromosome::{Chromosome, random_population},
+    crossover::crossover,
+    request::Request,
+    rng,
+};
+
+/// Runs NSGA-II treating every enabled fitness component as its own objective,
+/// returning the final Pareto front of the sentences that aren't on the blocklist.
+pub fn run(request: &Request) -> Vec<Chromosome> {
+    let population_count = CONFIG.population_count as usize;
+    let mut population: Vec<Chromosome> = random_population(request, CONFIG.population_count);
+    evaluate(request, &mut population);
+    let (mut ranks, mut distances) = rank_and_crowd(&population);
+
+    for _ in 0..CONFIG.generations {
+        let mut offspring: Vec<Chromosome> = Vec::with_capacity(population_count);
+        while offspring.len() < population_count {
+            let parent1 = &population[tournament(&ranks, &distances)];
+            let parent2 = &population[tournament(&ranks, &distances)];
+            let (mut child1, mut child2) = crossover(parent1, parent2);
+            child1.mutate(request);
+            child2.mutate(request);
+            offspring.push(child1);
+            offspring.push(child2);
+        }
+        offspring.truncate(population_count);
+        evaluate(request, &mut offspring);
+
+        population.append(&mut offspring);
+        population = survivors(population, population_count);
+        (ranks, distances) = rank_and_crowd(&population);
+    }
+
+    population.retain(|c| !blocklist::is_blocked(&c.genes, request));
+    if population.is_empty() {
+        return Vec::new();
+    }
+    let mut front: Vec<Chromosome> = non_dominated_sort(&population)
+        .swap_remove(0)
+        .into_iter()
+        .map(|i| population[i].clone())
+        .collect();
+    front.sort_by(|a, b| {
+        b.objectives
+            .partial_cmp(&a.objectives)
+            .unwrap_or(Ordering::Equal)
+    });
+    front
+}
+
+fn evaluate(request: &Request, population: &mut [Chromosome]) {
+    population.par_iter_mut().for_each(|c| c.evaluate(request));
+}
+
+/// Keeps the best `count` chromosomes by front, breaking ties in the last front by crowding distance.
+fn survivors(population: Vec<Chromosome>, count: usize) -> Vec<Chromosome> {
+    let mut selected: Vec<usize> = Vec::with_capacity(count);
+    for mut front in non_dominated_sort(&population) {
+        if selected.len() + front.len() > count {
+            let distances = crowding_distance(&population, &front);
+            let mut order: Vec<usize> = (0..front.len()).collect();
+            order.sort_by(|&a, &b| {
+                distances[b]
+                    .partial_cmp(&distances[a])
+                    .unwrap_or(Ordering::Equal)
+            });
+            front = order.into_iter().map(|i| front[i]).collect();
+            front.truncate(count - selected.len());
+        }
+        selected.extend(front);
+        if selected.len() == count {
+            break;
+        }
+    }
+    let mut population: Vec<Option<Chromosome>> = population.into_iter().map(Some).collect();
+    selected
+        .into_iter()
+        .map(|i| population[i].take().unwrap())
+        .collect()
+}
+
+fn rank_and_crowd(population: &[Chromosome]) -> (Vec<usize>, Vec<f32>) {
+    let mut ranks = vec![0; population.len()];
+    let mut distances = vec![0.0; population.len()];
+    for (rank, front) in non_dominated_sort(population).into_iter().enumerate() {
+        for (&i, distance) in front.iter().zip(crowding_distance(population, &front)) {
+            ranks[i] = rank;
+            distances[i] = distance;
+        }
+    }
+    (ranks, distances)
+}
+
+/// Binary tournament preferring the lower rank, then the larger crowding distance.
+fn tournament(ranks: &[usize], distances: &[f32]) -> usize {
+    let a = rng::random_range(0..ranks.len());
+    let b = rng::random_range(0..ranks.len());
+    if ranks[a] < ranks[b] || (ranks[a] == ranks[b] && distances[a] >= distances[b]) {
+        a
+    } else {
+        b
+    }
+}
+
+fn dominates(a: &Chromosome, b: &Chromosome) -> bool {
+    let mut strictly_better = false;
+    for (x, y) in a.objectives.iter().zip(&b.objectives) {
+        if x < y {
+            return false;
+        }
+        if x > y {
+            strictly_better = true;
+        }
+    }
+    strictly_better
+}
+
+/// Fast non-dominated sort, returns the indices of each front starting with the Pareto front.
+pub fn non_dominated_sort(population: &[Chromosome]) -> Vec<Vec<usize>> {
+    let mut dominated_by: Vec<Vec<usize>> = vec![Vec::new(); population.len()];
+    let mut domination_count: Vec<usize> = vec![0; population.len()];
+    let mut fronts: Vec<Vec<usize>> = vec![Vec::new()];
+
+    for p in 0..population.len() {
+        for q in 0..population.len() {
+            if dominates(&population[p], &population[q]) {
+                dominated_by[p].push(q);
+            } else if dominates(&population[q], &population[p]) {
+                domination_count[p] += 1;
+            }
+        }
+        if domination_count[p] == 0 {
+            fronts[0].push(p);
+        }
+    }
+
+    let mut current = 0;
+    while !fronts[current].is_empty() {
+        let mut next: Vec<usize> = Vec::new();
+        for &p in &fronts[current] {
+            for &q in &dominated_by[p] {
+                domination_count[q] -= 1;
+                if domination_count[q] == 0 {
+                    next.push(q);
+                }
+            }
+        }
+        fronts.push(next);
+        current += 1;
+    }
+    fronts.pop();
+    fronts
+}
+
+/// Crowding distance of each member of a front, boundary members get infinity.
+pub fn crowding_distance(population: &[Chromosome], front: &[usize]) -> Vec<f32> {
+    let mut distances = vec![0.0; front.len()];
+    if front.len() <= 2 {
+        return vec![f32::INFINITY; front.len()];
+    }
+    let objective_count = population[front[0]].objectives.len();
+    for m in 0..objective_count {
+        let objective = |i: usize| population[front[i]].objectives[m];
+        let mut order: Vec<usize> = (0..front.len()).collect();
+        order.sort_by(|&a, &b| {
+            objective(a)
+                .partial_cmp(&objective(b))
+                .unwrap_or(Ordering::Equal)
+        });
+        let first = order[0];
+        let last = order[order.len() - 1];
+        distances[first] = f32::INFINITY;
+        distances[last] = f32::INFINITY;
+        let range = objective(last) - objective(first);
+        if range <= 0.0 {
+            continue;
+        }
+        for window in order.windows(3) {
+            distances[window[1]] += (objective(window[2]) - objective(window[0])) / range;
+        }
+    }
+    distances
+}
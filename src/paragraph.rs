@@ -0,0 +1,44 @@
+use std::borrow::Cow;
+
+use crate::{
+    hall_of_fame::Entry,
+    request::Request,
+    select_structure,
+    strategy::{self, Strategy},
+};
+
+/// Generates sentences one after another, each scored for coherence with the ones before it,
+/// and returns the best of each.
+pub fn run(strategy: Strategy, sentences: usize, base: &Request) -> Vec<Entry> {
+    let mut paragraph: Vec<Entry> = Vec::with_capacity(sentences);
+    for _ in 0..sentences {
+        let history = paragraph
+            .iter()
+            .map(|e| e.chromosome.genes.clone())
+            .collect();
+        let structure = match &base.template {
+            Some(template) => Cow::Owned(template.structure()),
+            None => select_structure(base.mood, base.shape),
+        };
+        let request = Request {
+            structure,
+            history,
+            ..base.clone()
+        };
+        let hall_of_fame = strategy::run(strategy, &request);
+        match hall_of_fame.entries().first() {
+            Some(best) => paragraph.push(best.clone()),
+            None => break,
+        }
+    }
+    paragraph
+}
+
+/// Joins the sentences of a paragraph.
+pub fn join(paragraph: &[Entry]) -> String {
+    paragraph
+        .iter()
+        .map(|e| e.sentence.as_str())
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
@@ -1,36 +1,62 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
+use crate::{CONFIG, WordType, rng};
+
+// How far from 1.00 a group of rates can add up to in strict mode
+const STRICT_TOLERANCE: f32 = 0.001;
+
+/// A group of rates, which are weights relative to each other rather than probabilities.
 pub trait Rates {
-    fn add_up(&self) -> bool;
-    fn total(&self) -> f32;
+    fn values(&self) -> Vec<f32>;
+    fn total(&self) -> f32 {
+        self.values().iter().sum()
+    }
 }
 
-#[derive(Deserialize, Debug, Clone, Copy)]
+/// Rates for one word type each, missing ones are 0.
+#[derive(Deserialize, Debug, Clone, Copy, Default)]
+#[serde(default)]
 pub struct WordTypeRates {
     pub noun: f32,
+    pub pronoun: f32,
     pub verb: f32,
     pub adverb: f32,
     pub adjective: f32,
     pub preposition: f32,
     pub determiner: f32,
     pub conjunction: f32,
+    pub interjection: f32,
+    pub numeral: f32,
 }
 
 impl Rates for WordTypeRates {
-    fn add_up(&self) -> bool {
-        self.total().floor() <= 1.00
-    }
-    fn total(&self) -> f32 {
-        self.noun
-            + self.verb
-            + self.adverb
-            + self.adjective
-            + self.preposition
-            + self.determiner
-            + self.conjunction
+    fn values(&self) -> Vec<f32> {
+        vec![
+            self.noun,
+            self.pronoun,
+            self.verb,
+            self.adverb,
+            self.adjective,
+            self.preposition,
+            self.determiner,
+            self.conjunction,
+            self.interjection,
+            self.numeral,
+        ]
     }
 }
 
+/// Word type rates used instead of `word_type_rates` for the first word, or for the word
+/// after a given word type, when building new sentences.
+#[derive(Deserialize, Debug, Default)]
+pub struct PositionRates {
+    pub start: Option<WordTypeRates>,
+    #[serde(default)]
+    pub after: HashMap<WordType, WordTypeRates>,
+}
+
 #[derive(Deserialize, Debug, Clone, Copy)]
 pub struct NounRates {
     pub type_rates: NounTypeRates,
@@ -45,11 +71,8 @@ pub struct NounTypeRates {
     pub collective: f32,
 }
 impl Rates for NounTypeRates {
-    fn add_up(&self) -> bool {
-        self.total().floor() <= 1.00
-    }
-    fn total(&self) -> f32 {
-        self.common + self.proper + self.collective
+    fn values(&self) -> Vec<f32> {
+        vec![self.common, self.proper, self.collective]
     }
 }
 #[derive(Deserialize, Debug, Clone, Copy)]
@@ -58,11 +81,8 @@ pub struct NounTangibilityRates {
     pub r#abstract: f32,
 }
 impl Rates for NounTangibilityRates {
-    fn add_up(&self) -> bool {
-        self.total().floor() <= 1.00
-    }
-    fn total(&self) -> f32 {
-        self.concrete + self.concrete
+    fn values(&self) -> Vec<f32> {
+        vec![self.concrete, self.r#abstract]
     }
 }
 #[derive(Deserialize, Debug, Clone, Copy)]
@@ -71,11 +91,33 @@ pub struct NounCountabilityRates {
     pub uncountable: f32,
 }
 impl Rates for NounCountabilityRates {
-    fn add_up(&self) -> bool {
-        self.total().floor() <= 1.00
+    fn values(&self) -> Vec<f32> {
+        vec![self.countable, self.uncountable]
     }
-    fn total(&self) -> f32 {
-        self.countable + self.uncountable
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct PronounTypeRates {
+    pub personal: f32,
+    pub possessive: f32,
+    pub reflexive: f32,
+    pub relative: f32,
+    pub demonstrative: f32,
+    pub interrogative: f32,
+    pub indefinite: f32,
+}
+
+impl Rates for PronounTypeRates {
+    fn values(&self) -> Vec<f32> {
+        vec![
+            self.personal,
+            self.possessive,
+            self.reflexive,
+            self.relative,
+            self.demonstrative,
+            self.interrogative,
+            self.indefinite,
+        ]
     }
 }
 
@@ -92,18 +134,17 @@ pub struct AdjectiveTypeRates {
 }
 
 impl Rates for AdjectiveTypeRates {
-    fn add_up(&self) -> bool {
-        self.total().floor() <= 1.00
-    }
-    fn total(&self) -> f32 {
-        self.interrogative
-            + self.distributive
-            + self.numeral
-            + self.proper
-            + self.descriptive
-            + self.possessive
-            + self.quantitative
-            + self.demonstrative
+    fn values(&self) -> Vec<f32> {
+        vec![
+            self.interrogative,
+            self.distributive,
+            self.numeral,
+            self.proper,
+            self.descriptive,
+            self.possessive,
+            self.quantitative,
+            self.demonstrative,
+        ]
     }
 }
 
@@ -120,18 +161,17 @@ pub struct VerbTypeRates {
 }
 
 impl Rates for VerbTypeRates {
-    fn add_up(&self) -> bool {
-        self.total().floor() <= 1.00
-    }
-    fn total(&self) -> f32 {
-        self.action
-            + self.transitive
-            + self.intransitive
-            + self.auxiliary
-            + self.linking
-            + self.modal
-            + self.regular
-            + self.irregular
+    fn values(&self) -> Vec<f32> {
+        vec![
+            self.action,
+            self.transitive,
+            self.intransitive,
+            self.auxiliary,
+            self.linking,
+            self.modal,
+            self.regular,
+            self.irregular,
+        ]
     }
 }
 
@@ -146,11 +186,15 @@ pub struct AdverbTypeRates {
 }
 
 impl Rates for AdverbTypeRates {
-    fn add_up(&self) -> bool {
-        self.total().floor() <= 1.00
-    }
-    fn total(&self) -> f32 {
-        self.time + self.frequency + self.place + self.degree + self.manner + self.conjunctive
+    fn values(&self) -> Vec<f32> {
+        vec![
+            self.time,
+            self.frequency,
+            self.place,
+            self.degree,
+            self.manner,
+            self.conjunctive,
+        ]
     }
 }
 
@@ -162,11 +206,8 @@ pub struct PrepositionTypeRates {
     pub purpose: f32,
 }
 impl Rates for PrepositionTypeRates {
-    fn add_up(&self) -> bool {
-        self.total().floor() <= 1.00
-    }
-    fn total(&self) -> f32 {
-        self.place + self.time + self.movement + self.purpose
+    fn values(&self) -> Vec<f32> {
+        vec![self.place, self.time, self.movement, self.purpose]
     }
 }
 
@@ -183,18 +224,17 @@ pub struct DeterminerTypeRates {
 }
 
 impl Rates for DeterminerTypeRates {
-    fn add_up(&self) -> bool {
-        self.total().floor() <= 1.00
-    }
-    fn total(&self) -> f32 {
-        self.interrogative
-            + self.demonstrative
-            + self.distributive
-            + self.relative
-            + self.possessive
-            + self.article
-            + self.quantifier
-            + self.negative
+    fn values(&self) -> Vec<f32> {
+        vec![
+            self.interrogative,
+            self.demonstrative,
+            self.distributive,
+            self.relative,
+            self.possessive,
+            self.article,
+            self.quantifier,
+            self.negative,
+        ]
     }
 }
 
@@ -205,23 +245,114 @@ pub struct ConjunctionTypeRates {
     pub correlative: f32,
 }
 impl Rates for ConjunctionTypeRates {
-    fn add_up(&self) -> bool {
-        self.total().floor() <= 1.00
+    fn values(&self) -> Vec<f32> {
+        vec![self.subordinating, self.coordinating, self.correlative]
     }
-    fn total(&self) -> f32 {
-        self.subordinating + self.coordinating + self.correlative
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct InterjectionTypeRates {
+    pub emotion: f32,
+    pub greeting: f32,
+    pub agreement: f32,
+    pub hesitation: f32,
+}
+impl Rates for InterjectionTypeRates {
+    fn values(&self) -> Vec<f32> {
+        vec![self.emotion, self.greeting, self.agreement, self.hesitation]
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct NumeralTypeRates {
+    pub cardinal: f32,
+    pub ordinal: f32,
+}
+impl Rates for NumeralTypeRates {
+    fn values(&self) -> Vec<f32> {
+        vec![self.cardinal, self.ordinal]
     }
 }
 
-pub fn check_rates<'a, B>(name: &'a str, rates: &B)
+/// Exits if a rate isn't a finite number or is negative, or in strict mode if the group doesn't
+/// add up to 1.00, and warns when every rate is 0 as the group is then picked from evenly.
+pub fn check_rates<B>(name: &str, rates: &B)
 where
     B: Rates,
 {
-    if !rates.add_up() {
-        eprintln!(
-            "{name:#}  rates don't add up to be under 1.00 Total = {}",
-            rates.total()
-        );
+    if let Some(error) = rates_error(name, rates, CONFIG.strict_rates) {
+        eprintln!("{error}");
         std::process::exit(1);
     }
+    if rates.total() == 0.00 {
+        eprintln!("Warning: {name} rates are all 0, picking from them evenly");
+    }
+}
+
+/// Why a group of rates can't be used, if it can't.
+pub fn rates_error<B>(name: &str, rates: &B, strict: bool) -> Option<String>
+where
+    B: Rates,
+{
+    if rates.values().iter().any(|rate| !rate.is_finite()) {
+        return Some(format!("{name} rates have to be finite numbers"));
+    }
+    if rates.values().iter().any(|&rate| rate < 0.00) {
+        return Some(format!("{name} rates can't be negative"));
+    }
+    let total = rates.total();
+    if strict && (total - 1.00).abs() > STRICT_TOLERANCE {
+        return Some(format!("{name} rates don't add up to 1.00 Total = {total}"));
+    }
+    None
+}
+
+/// Scales rates to add up to 1.00, rates that are all 0 are treated as equal.
+pub fn normalise<K>(rates: Vec<(f32, K)>) -> Vec<(f32, K)> {
+    let total: f32 = rates.iter().map(|(rate, _)| rate).sum();
+    let count = rates.len() as f32;
+    rates
+        .into_iter()
+        .map(|(rate, key)| {
+            let rate = if total > 0.00 {
+                rate / total
+            } else {
+                1.00 / count
+            };
+            (rate, key)
+        })
+        .collect()
+}
+
+/// Leaves out the keys without words and, unless every rate is 0, the ones at 0, so a pick
+/// from their thresholds never lands on an empty group.
+pub fn available<K: Copy>(rates: Vec<(f32, K)>, has_words: impl Fn(K) -> bool) -> Vec<(f32, K)> {
+    let all_zero = rates.iter().all(|&(rate, _)| rate == 0.00);
+    rates
+        .into_iter()
+        .filter(|&(rate, key)| has_words(key) && (all_zero || rate > 0.00))
+        .collect()
+}
+
+/// Turns rates into cumulative thresholds, a random number up to 1.00 picks the first
+/// threshold it is under.
+pub fn thresholds<K>(rates: Vec<(f32, K)>) -> Vec<(f32, K)> {
+    let mut total: f32 = 0.00;
+    normalise(rates)
+        .into_iter()
+        .map(|(rate, key)| {
+            total += rate;
+            (total, key)
+        })
+        .collect()
+}
+
+/// Picks a key from cumulative thresholds, the last one if rounding leaves a gap at the end.
+pub fn pick<K: Copy>(thresholds: &[(f32, K)]) -> K {
+    let random_f32: f32 = rng::random_range(0.00..1.00);
+    thresholds
+        .iter()
+        .find(|(threshold, _)| random_f32 <= *threshold)
+        .unwrap_or(&thresholds[thresholds.len() - 1])
+        .1
 }
@@ -0,0 +1,172 @@
+use serde::Deserialize;
+
+use crate::{
+    CONFIG, WordType,
+    chromosome::GeneType,
+    clause::{self, Shape},
+    request::Request,
+    words::{ADJECTIVES, ADVERBS, AdjectiveType, AdverbType, ConjunctionType, NOUNS, NounType},
+};
+
+#[derive(Deserialize, Debug, Default)]
+pub struct RenderingConfig {
+    /// Joins word pairs like "do not" into "don't".
+    #[serde(default)]
+    pub contractions: bool,
+    /// Ends every sentence with this instead of the mood's punctuation.
+    pub terminator: Option<String>,
+}
+
+#[rustfmt::skip]
+const CONTRACTIONS: &[(&str, &str, &str)] = &[
+    ("is", "not", "isn't"), ("are", "not", "aren't"), ("was", "not", "wasn't"),
+    ("were", "not", "weren't"), ("do", "not", "don't"), ("does", "not", "doesn't"),
+    ("did", "not", "didn't"), ("have", "not", "haven't"), ("has", "not", "hasn't"),
+    ("had", "not", "hadn't"), ("will", "not", "won't"), ("would", "not", "wouldn't"),
+    ("should", "not", "shouldn't"), ("could", "not", "couldn't"), ("can", "not", "can't"),
+    ("must", "not", "mustn't"),
+    ("I", "am", "I'm"), ("you", "are", "you're"), ("we", "are", "we're"),
+    ("they", "are", "they're"), ("he", "is", "he's"), ("she", "is", "she's"),
+    ("it", "is", "it's"), ("I", "have", "I've"), ("you", "have", "you've"),
+    ("we", "have", "we've"), ("they", "have", "they've"), ("I", "will", "I'll"),
+    ("you", "will", "you'll"), ("he", "will", "he'll"), ("she", "will", "she'll"),
+    ("we", "will", "we'll"), ("they", "will", "they'll"),
+];
+
+/// Turns genes into a finished sentence: casing, commas, contractions and the terminator.
+pub fn render(genes: &[GeneType], request: &Request) -> String {
+    if genes.is_empty() {
+        return String::new();
+    }
+    let mut words: Vec<String> = genes
+        .iter()
+        .map(|g| {
+            let text = g.text();
+            if keeps_capitals(*g) {
+                text.into_owned()
+            } else {
+                text.to_lowercase()
+            }
+        })
+        .collect();
+    for position in comma_positions(genes, request.shape) {
+        words[position].push(',');
+    }
+    if CONFIG.rendering.contractions {
+        words = contract(words);
+    }
+    words[0] = capitalize(&words[0]);
+    let mut sentence = words.join(" ");
+    match &CONFIG.rendering.terminator {
+        Some(terminator) => sentence.push_str(terminator),
+        None => sentence.push(request.mood.terminator()),
+    }
+    sentence
+}
+
+/// Upper-cases the first character, which may become more than one character.
+pub fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        None => String::new(),
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+    }
+}
+
+/// Proper nouns, proper adjectives and "I" are capitalised wherever they are.
+fn keeps_capitals(gene: GeneType) -> bool {
+    let index = gene.index as usize;
+    match gene.word_type {
+        WordType::Noun => NOUNS.words[index].r#type == NounType::Proper,
+        WordType::Adjective => ADJECTIVES.words[index].r#type == AdjectiveType::Proper,
+        WordType::Pronoun => gene.text() == "I",
+        _ => false,
+    }
+}
+
+/// The words a comma goes after: an opening interjection or adverb, both sides of a
+/// conjunctive adverb, and the end of the first clause.
+fn comma_positions(genes: &[GeneType], shape: Shape) -> Vec<usize> {
+    let last = genes.len() - 1;
+    let mut positions = Vec::new();
+    let introductory = match genes[0].word_type {
+        WordType::Interjection => true,
+        WordType::Adverb => adverb_type(genes[0]) != AdverbType::Degree,
+        _ => false,
+    };
+    if introductory && last > 0 {
+        positions.push(0);
+    }
+    for (i, &gene) in genes
+        .iter()
+        .enumerate()
+        .skip(1)
+        .take(last.saturating_sub(1))
+    {
+        if gene.word_type == WordType::Adverb && adverb_type(gene) == AdverbType::Conjunctive {
+            positions.extend([i - 1, i]);
+        }
+    }
+    if let Some(position) = clause_boundary(genes, shape) {
+        positions.push(position);
+    }
+    positions.sort_unstable();
+    positions.dedup();
+    positions
+}
+
+/// The last word of the first clause when the sentence has two. Compound sentences have the
+/// comma before their coordinating conjunction, and a sentence opening with a subordinate
+/// clause has it before the main clause's subject.
+fn clause_boundary(genes: &[GeneType], shape: Shape) -> Option<usize> {
+    if shape == Shape::Compound {
+        return genes
+            .iter()
+            .skip(1)
+            .position(|&g| clause::conjunction_type_of(g) == Some(ConjunctionType::Coordinating));
+    }
+    if clause::conjunction_type_of(genes[0]) != Some(ConjunctionType::Subordinating) {
+        return None;
+    }
+    let first_verb = genes.iter().position(|g| g.word_type == WordType::Verb)?;
+    (first_verb + 1..genes.len())
+        .rev()
+        .find(|&i| starts_noun_phrase(genes, i) && clause::is_clause(&genes[i..]))
+        .map(|i| i - 1)
+}
+
+fn starts_noun_phrase(genes: &[GeneType], index: usize) -> bool {
+    let modifier = |g: &GeneType| {
+        matches!(
+            g.word_type,
+            WordType::Determiner | WordType::Adjective | WordType::Numeral
+        )
+    };
+    (modifier(&genes[index])
+        || matches!(genes[index].word_type, WordType::Noun | WordType::Pronoun))
+        && !modifier(&genes[index - 1])
+}
+
+fn adverb_type(gene: GeneType) -> AdverbType {
+    ADVERBS.words[gene.index as usize].r#type
+}
+
+/// Joins each pair of words in `CONTRACTIONS`, unless a comma separates them.
+fn contract(words: Vec<String>) -> Vec<String> {
+    let mut contracted: Vec<String> = Vec::with_capacity(words.len());
+    for word in words {
+        let joined = contracted.last().and_then(|previous| {
+            CONTRACTIONS
+                .iter()
+                .find(|(first, second, _)| previous == first && word == *second)
+        });
+        match joined {
+            Some((_, _, contraction)) => {
+                let previous = contracted.last_mut().expect("a previous word was matched");
+                *previous = contraction.to_string();
+            }
+            None => contracted.push(word),
+        }
+    }
+    contracted
+}
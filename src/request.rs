@@ -0,0 +1,108 @@
+use std::{borrow::Cow, process};
+
+use lazy_static::lazy_static;
+
+use crate::{
+    CONFIG, WordType,
+    chromosome::GeneType,
+    clause::Shape,
+    mood::Mood,
+    required::{self, RequiredWord},
+    select_structure,
+    template::Template,
+    tense::{Aspect, Tense},
+};
+
+/// Settings that can change from one run to the next without editing the config, they
+/// default to the config's values.
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub tense: Option<Tense>,
+    pub aspect: Option<Aspect>,
+    pub mood: Mood,
+    pub shape: Shape,
+    /// The structure sentences are scored against, picked for the mood and shape.
+    pub structure: Cow<'static, [WordType]>,
+    /// The sentences already generated for the paragraph, oldest first.
+    pub history: Vec<Vec<GeneType>>,
+    /// Words every sentence has to contain.
+    pub required_words: Vec<RequiredWord>,
+    /// Whether the required words have to appear in the order given.
+    pub ordered: bool,
+    /// A partial sentence whose slots are the only words generated.
+    pub template: Option<Template>,
+}
+
+impl Request {
+    pub fn new(tense: Option<Tense>, aspect: Option<Aspect>, mood: Mood, shape: Shape) -> Self {
+        if CONFIG.word_count < shape.minimum_word_count() {
+            eprintln!(
+                "{shape:?} sentences need a word count of at least {}",
+                shape.minimum_word_count()
+            );
+            process::exit(1);
+        }
+        Request {
+            tense,
+            aspect,
+            mood,
+            shape,
+            structure: select_structure(mood, shape),
+            history: Vec::new(),
+            required_words: Vec::new(),
+            ordered: false,
+            template: None,
+        }
+    }
+
+    /// Generates only the slots of a template, which also becomes the structure.
+    pub fn with_template(self, template: Option<&str>) -> Self {
+        let Some(template) = template.map(Template::parse) else {
+            return self;
+        };
+        Request {
+            structure: Cow::Owned(template.structure()),
+            template: Some(template),
+            ..self
+        }
+    }
+
+    /// How many words sentences have, the template's length when there is one.
+    pub fn word_count(&self) -> usize {
+        match &self.template {
+            Some(template) => template.slots.len(),
+            None => CONFIG.word_count,
+        }
+    }
+
+    /// Requires every sentence to contain the given words, exiting if they can't fit.
+    pub fn with_required_words(self, words: &[String], ordered: bool) -> Self {
+        if words.len() > self.word_count() {
+            eprintln!(
+                "{} required words don't fit in a word count of {}",
+                words.len(),
+                self.word_count()
+            );
+            process::exit(1);
+        }
+        Request {
+            required_words: required::resolve(words),
+            ordered,
+            ..self
+        }
+    }
+}
+
+impl Default for Request {
+    fn default() -> Self {
+        Request::new(CONFIG.tense, CONFIG.aspect, CONFIG.mood, CONFIG.shape)
+            .with_template(CONFIG.template.as_deref())
+            .with_required_words(&CONFIG.required_words, CONFIG.ordered_required_words)
+    }
+}
+
+lazy_static! {
+    /// The request built from the config, only for genetica's traits which have no way to be
+    /// given one. Everything else is passed its request.
+    pub static ref DEFAULT: Request = Request::default();
+}
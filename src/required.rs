@@ -0,0 +1,111 @@
+use std::process;
+
+use crate::{
+    WordType,
+    chromosome::{GeneType, genes_written_as},
+    rng,
+};
+
+/// A word every sentence has to contain, with each lexicon entry and form it could be.
+#[derive(Debug, Clone)]
+pub struct RequiredWord {
+    pub text: String,
+    pub genes: Vec<GeneType>,
+}
+
+impl RequiredWord {
+    /// Whether a gene is written as this word, in any part of speech.
+    pub fn matches(&self, gene: &GeneType) -> bool {
+        gene.text().eq_ignore_ascii_case(&self.text)
+    }
+    /// One of the genes the word could be, preferring the given word type.
+    fn gene(&self, word_type: Option<WordType>) -> GeneType {
+        let of_type: Vec<&GeneType> = self
+            .genes
+            .iter()
+            .filter(|g| Some(g.word_type) == word_type)
+            .collect();
+        if of_type.is_empty() {
+            self.genes[rng::random_range(0..self.genes.len())]
+        } else {
+            *of_type[rng::random_range(0..of_type.len())]
+        }
+    }
+}
+
+/// Finds every lexicon entry and form written as each word, exiting if one isn't in it.
+pub fn resolve(words: &[String]) -> Vec<RequiredWord> {
+    words
+        .iter()
+        .map(|text| {
+            let genes = genes_written_as(text);
+            if genes.is_empty() {
+                eprintln!("Required word {text:?} is not in the lexicon");
+                process::exit(1);
+            }
+            RequiredWord {
+                text: text.clone(),
+                genes,
+            }
+        })
+        .collect()
+}
+
+/// The share of required words the sentence contains, 1.00 is all of them. When `ordered`,
+/// only words found after the previous required word count.
+pub fn score(genes: &[GeneType], required: &[RequiredWord], ordered: bool) -> f32 {
+    if required.is_empty() {
+        return 1.0;
+    }
+    let mut used = vec![false; genes.len()];
+    let mut start = 0;
+    let mut found = 0;
+    for word in required {
+        let position = (start..genes.len()).find(|&i| !used[i] && word.matches(&genes[i]));
+        if let Some(position) = position {
+            used[position] = true;
+            found += 1;
+            if ordered {
+                start = position + 1;
+            }
+        }
+    }
+    found as f32 / required.len() as f32
+}
+
+/// Puts each required word into the sentence, at a position whose structure word type it can
+/// be where possible, keeping them in order when `ordered`.
+pub fn place(
+    genes: &mut [GeneType],
+    required: &[RequiredWord],
+    ordered: bool,
+    structure: &[WordType],
+) {
+    let mut used = vec![false; genes.len()];
+    let mut positions: Vec<usize> = Vec::with_capacity(required.len());
+    for word in required {
+        let free: Vec<usize> = (0..genes.len()).filter(|&i| !used[i]).collect();
+        let fitting: Vec<usize> = free
+            .iter()
+            .copied()
+            .filter(|&i| {
+                structure
+                    .get(i)
+                    .is_some_and(|wt| word.genes.iter().any(|g| g.word_type == *wt))
+            })
+            .collect();
+        let candidates = if fitting.is_empty() { free } else { fitting };
+        if candidates.is_empty() {
+            break;
+        }
+        let position = candidates[rng::random_range(0..candidates.len())];
+        used[position] = true;
+        positions.push(position);
+    }
+    if ordered {
+        positions.sort_unstable();
+    }
+    for (word, position) in required.iter().zip(positions) {
+        genes[position] = word.gene(structure.get(position).copied());
+    }
+}
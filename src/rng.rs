@@ -0,0 +1,28 @@
+use std::cell::RefCell;
+
+use rand::{
+    Rng, SeedableRng,
+    distr::uniform::{SampleRange, SampleUniform},
+    rngs::StdRng,
+};
+
+thread_local! {
+    static RNG: RefCell<StdRng> = RefCell::new(StdRng::from_os_rng());
+}
+
+/// Seeds the current thread's generator so runs on this thread can be repeated.
+pub fn seed(seed: u64) {
+    RNG.with(|rng| *rng.borrow_mut() = StdRng::seed_from_u64(seed));
+}
+
+pub fn random_range<T, R>(range: R) -> T
+where
+    T: SampleUniform,
+    R: SampleRange<T>,
+{
+    RNG.with(|rng| rng.borrow_mut().random_range(range))
+}
+
+pub fn random_bool(p: f64) -> bool {
+    RNG.with(|rng| rng.borrow_mut().random_bool(p))
+}
@@ -0,0 +1,227 @@
+use std::{collections::HashSet, process};
+
+use genetica::population::sort_population_descending;
+use rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
+
+use crate::{
+    CONFIG, WordType,
+    chromosome::{Chromosome, GeneType, allowed_forms, random_population, select_word_of_type},
+    hall_of_fame::HallOfFame,
+    mood,
+    request::Request,
+    template::Slot,
+    words::collection,
+};
+
+// Past this many sentences the exhaustive search would take too long
+const EXHAUSTIVE_LIMIT: usize = 10_000_000;
+
+fn evaluate(request: &Request, population: &mut Vec<Chromosome>) {
+    population.par_iter_mut().for_each(|c| c.evaluate(request));
+    sort_population_descending(population);
+}
+
+/// Evaluates as many random chromosomes as the genetic algorithm would over a run.
+pub fn random(request: &Request, hall_of_fame: &mut HallOfFame) {
+    for _ in 0..CONFIG.generations {
+        let mut population: Vec<Chromosome> = random_population(request, CONFIG.population_count);
+        evaluate(request, &mut population);
+        hall_of_fame.update(&population, request);
+    }
+}
+
+/// The word types a position can take, only the slot's type with a template.
+fn position_types(request: &Request, position: usize) -> Vec<WordType> {
+    match &request.template {
+        Some(template) => vec![template.slots[position].word_type()],
+        None => WordType::ALL.to_vec(),
+    }
+}
+
+/// A word of the type to follow the words `before` it, from the template's slot when there is
+/// one.
+fn sample(request: &Request, before: &[GeneType], word_type: WordType) -> GeneType {
+    match &request.template {
+        Some(template) => template.slots[before.len()].select(request, before),
+        None => select_word_of_type(request, word_type, before),
+    }
+}
+
+/// Builds sentences left to right, extending every partial sentence with sampled words
+/// of each type and keeping only the best `beam_width` after each position.
+pub fn beam(request: &Request, hall_of_fame: &mut HallOfFame) {
+    let mut beam: Vec<Chromosome> = vec![Chromosome::from_genes(Vec::new())];
+    for position in 0..request.word_count() {
+        let mut candidates: Vec<Chromosome> = Vec::new();
+        for partial in &beam {
+            if request.template.is_none() {
+                for gene in request.required_words.iter().flat_map(|w| &w.genes) {
+                    let mut genes = partial.genes.clone();
+                    genes.push(*gene);
+                    candidates.push(Chromosome::from_genes(genes));
+                }
+            }
+            for word_type in position_types(request, position) {
+                for _ in 0..CONFIG.beam_candidates {
+                    let mut genes = partial.genes.clone();
+                    genes.push(sample(request, &partial.genes, word_type));
+                    candidates.push(Chromosome::from_genes(genes));
+                }
+            }
+        }
+        evaluate(request, &mut candidates);
+
+        let mut seen: HashSet<Vec<GeneType>> = HashSet::new();
+        candidates.retain(|c| seen.insert(c.genes.clone()));
+        candidates.truncate(CONFIG.beam_width);
+        beam = candidates;
+    }
+    hall_of_fame.update(&beam, request);
+}
+
+/// Whether any word before the position could be an auxiliary or modal, any could be without
+/// a template.
+fn follows_possible_auxiliary(request: &Request, position: usize) -> bool {
+    let Some(template) = &request.template else {
+        return position > 0;
+    };
+    template.slots[..position].iter().any(|slot| match slot {
+        Slot::Fixed(gene) => mood::is_auxiliary(*gene),
+        Slot::Open { word_type, words } => {
+            let indices: Vec<u32> = match words {
+                Some(words) => words.iter().map(|&(_, index)| index).collect(),
+                None => (0..collection(*word_type).size()).collect(),
+            };
+            indices.into_iter().any(|index| {
+                collection(*word_type)
+                    .word(index)
+                    .forms()
+                    .iter()
+                    .any(|&form| {
+                        mood::is_auxiliary(GeneType {
+                            word_type: *word_type,
+                            index,
+                            form,
+                        })
+                    })
+            })
+        }
+    })
+}
+
+/// Every word and form a position can take, only the slot's words with a template. Forms
+/// are limited to the requested tense the same way sampling limits them, unless an auxiliary
+/// or modal could come before the position.
+fn position_genes(request: &Request, position: usize) -> Vec<GeneType> {
+    let entries: Vec<(WordType, u32)> = match request.template.as_ref().map(|t| &t.slots[position])
+    {
+        Some(Slot::Fixed(gene)) => return vec![*gene],
+        Some(Slot::Open {
+            word_type,
+            words: Some(words),
+        }) => words
+            .iter()
+            .map(|&(_, index)| (*word_type, index))
+            .collect(),
+        Some(Slot::Open {
+            word_type,
+            words: None,
+        }) => (0..collection(*word_type).size())
+            .map(|index| (*word_type, index))
+            .collect(),
+        None => WordType::ALL
+            .into_iter()
+            .flat_map(|word_type| {
+                (0..collection(word_type).size()).map(move |index| (word_type, index))
+            })
+            .collect(),
+    };
+    let finite = !follows_possible_auxiliary(request, position);
+    entries
+        .into_iter()
+        .flat_map(|(word_type, index)| {
+            allowed_forms(request, collection(word_type).word(index).forms(), finite)
+                .into_iter()
+                .map(move |form| GeneType {
+                    word_type,
+                    index,
+                    form,
+                })
+        })
+        .collect()
+}
+
+/// How many sentences the exhaustive search would evaluate, `None` past `EXHAUSTIVE_LIMIT`.
+pub fn exhaustive_size(request: &Request) -> Option<usize> {
+    (0..request.word_count())
+        .try_fold(1_usize, |total, position| {
+            total.checked_mul(position_genes(request, position).len())
+        })
+        .filter(|&total| total <= EXHAUSTIVE_LIMIT)
+}
+
+/// Evaluates every sentence of every word and form in each position, exiting if there are
+/// more than `EXHAUSTIVE_LIMIT` of them.
+pub fn exhaustive(request: &Request, hall_of_fame: &mut HallOfFame) {
+    let word_count = request.word_count();
+    let Some(combinations) = exhaustive_size(request) else {
+        eprintln!(
+            "Exhaustive search over {word_count} words is too large, try a smaller word count or a template"
+        );
+        process::exit(1);
+    };
+    let genes: Vec<Vec<GeneType>> = (0..word_count)
+        .map(|position| position_genes(request, position))
+        .collect();
+
+    let chunk_size = CONFIG.population_count.max(1) as usize;
+    let mut start = 0;
+    while start < combinations {
+        let end = (start + chunk_size).min(combinations);
+        let mut population: Vec<Chromosome> = (start..end)
+            .map(|mut index| {
+                let sentence = genes
+                    .iter()
+                    .map(|genes| {
+                        let gene = genes[index % genes.len()];
+                        index /= genes.len();
+                        gene
+                    })
+                    .collect();
+                Chromosome::from_genes(sentence)
+            })
+            .collect();
+        evaluate(request, &mut population);
+        hall_of_fame.update(&population, request);
+        start = end;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        clause::Shape,
+        mood::Mood,
+        morphology::Form,
+        tense::{Tense, is_finite_after},
+    };
+
+    #[test]
+    fn verb_after_did_can_take_its_base_form_in_the_past() {
+        let request = Request::new(Some(Tense::Past), None, Mood::Interrogative, Shape::Simple)
+            .with_template(Some("did {Pronoun:Personal} {Verb:Action}"));
+        let verbs = position_genes(&request, 2);
+        assert!(
+            verbs
+                .iter()
+                .any(|g| g.form == Form::Base && g.text() == "run")
+        );
+
+        let before: Vec<GeneType> = (0..2)
+            .map(|position| position_genes(&request, position)[0])
+            .collect();
+        assert!(!is_finite_after(&before));
+        assert!(allowed_forms(&request, &[Form::Base, Form::Past], false).contains(&Form::Base));
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    WordType,
+    chromosome::GeneType,
+    words::{Feature, NOUNS, PRONOUNS, VERBS},
+};
+
+/// Scores how many of the verbs' expectations of their subjects and objects are met,
+/// 1.00 is all of them or none to check.
+pub fn score(genes: &[GeneType]) -> f32 {
+    let mut checked = 0;
+    let mut satisfied = 0;
+    for (i, gene) in genes.iter().enumerate() {
+        if gene.word_type != WordType::Verb {
+            continue;
+        }
+        let attributes = &VERBS.words[gene.index as usize].attributes;
+        let arguments = [
+            (attributes.subject, subject(genes, i)),
+            (attributes.object, object(genes, i)),
+        ];
+        for (expected, argument) in arguments {
+            let (Some(expected), Some(features)) = (expected, argument.and_then(features)) else {
+                continue;
+            };
+            checked += 1;
+            if expected.satisfied_by(features) {
+                satisfied += 1;
+            }
+        }
+    }
+    if checked == 0 {
+        1.0
+    } else {
+        satisfied as f32 / checked as f32
+    }
+}
+
+fn is_argument(gene: &GeneType) -> bool {
+    matches!(gene.word_type, WordType::Noun | WordType::Pronoun)
+}
+
+/// The nearest noun or pronoun before the verb in the same clause.
+fn subject(genes: &[GeneType], verb: usize) -> Option<&GeneType> {
+    genes[..verb]
+        .iter()
+        .rev()
+        .take_while(|g| g.word_type != WordType::Conjunction)
+        .find(|g| is_argument(g))
+}
+
+/// The nearest noun or pronoun after the verb, before a preposition takes it as its object.
+fn object(genes: &[GeneType], verb: usize) -> Option<&GeneType> {
+    genes[verb + 1..]
+        .iter()
+        .take_while(|g| {
+            !matches!(
+                g.word_type,
+                WordType::Conjunction | WordType::Preposition | WordType::Verb
+            )
+        })
+        .find(|g| is_argument(g))
+}
+
+/// The features of a noun or pronoun, `None` when it has none to check.
+fn features(gene: &GeneType) -> Option<&'static [Feature]> {
+    let features = match gene.word_type {
+        WordType::Noun => &NOUNS.words[gene.index as usize].attributes.features,
+        WordType::Pronoun => &PRONOUNS.words[gene.index as usize].attributes.features,
+        _ => return None,
+    };
+    (!features.is_empty()).then_some(features.as_slice())
+}
@@ -0,0 +1,130 @@
+use std::{fmt, str::FromStr, time::Instant};
+
+use crate::{CONFIG, ga, hall_of_fame::HallOfFame, local_search, request::Request, search};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Strategy {
+    #[default]
+    Ga,
+    Random,
+    Beam,
+    Exhaustive,
+    Annealing,
+    HillClimbing,
+    GaAnnealing,
+}
+
+impl Strategy {
+    pub const ALL: [Strategy; 7] = [
+        Strategy::Ga,
+        Strategy::Random,
+        Strategy::Beam,
+        Strategy::Exhaustive,
+        Strategy::Annealing,
+        Strategy::HillClimbing,
+        Strategy::GaAnnealing,
+    ];
+}
+
+impl FromStr for Strategy {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ga" => Ok(Strategy::Ga),
+            "random" => Ok(Strategy::Random),
+            "beam" => Ok(Strategy::Beam),
+            "exhaustive" => Ok(Strategy::Exhaustive),
+            "annealing" => Ok(Strategy::Annealing),
+            "hill-climbing" => Ok(Strategy::HillClimbing),
+            "ga-annealing" => Ok(Strategy::GaAnnealing),
+            _ => Err(format!(
+                "Unknown strategy {s}, expected one of exhaustive, beam, random, ga, annealing, hill-climbing or ga-annealing"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Strategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Strategy::Ga => "ga",
+            Strategy::Random => "random",
+            Strategy::Beam => "beam",
+            Strategy::Exhaustive => "exhaustive",
+            Strategy::Annealing => "annealing",
+            Strategy::HillClimbing => "hill-climbing",
+            Strategy::GaAnnealing => "ga-annealing",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// Runs a strategy and returns the best sentences it found.
+pub fn run(strategy: Strategy, request: &Request) -> HallOfFame {
+    let mut hall_of_fame = HallOfFame::new(CONFIG.hall_of_fame_size);
+    match strategy {
+        Strategy::Ga => ga::run(request, &mut hall_of_fame),
+        Strategy::Random => search::random(request, &mut hall_of_fame),
+        Strategy::Beam => search::beam(request, &mut hall_of_fame),
+        Strategy::Exhaustive => search::exhaustive(request, &mut hall_of_fame),
+        Strategy::Annealing => local_search::annealing(request, &mut hall_of_fame),
+        Strategy::HillClimbing => local_search::hill_climbing(request, &mut hall_of_fame),
+        Strategy::GaAnnealing => {
+            ga::run(request, &mut hall_of_fame);
+            if let Some(best) = hall_of_fame.entries().first() {
+                let best = best.chromosome.clone();
+                local_search::anneal_from(request, best, &mut hall_of_fame);
+            }
+        }
+    }
+    hall_of_fame
+}
+
+/// Runs every strategy with the same config and prints how they compare. Exhaustive search is
+/// skipped with a note when it would be too large, leaving the gaps out.
+pub fn compare(request: &Request) {
+    let exhaustive_fits = search::exhaustive_size(request).is_some();
+    let results: Vec<(Strategy, f32, f32, String)> = Strategy::ALL
+        .iter()
+        .filter(|&&strategy| strategy != Strategy::Exhaustive || exhaustive_fits)
+        .map(|&strategy| {
+            let start = Instant::now();
+            let hall_of_fame = run(strategy, request);
+            let seconds = start.elapsed().as_secs_f32();
+            let (fitness, sentence) = match hall_of_fame.entries().first() {
+                Some(best) => (best.fitness(), best.sentence.clone()),
+                None => (0.00, "Every sentence matched the blocklist".to_string()),
+            };
+            (strategy, fitness, seconds, sentence)
+        })
+        .collect();
+
+    let optimum = results
+        .iter()
+        .find(|(strategy, ..)| *strategy == Strategy::Exhaustive)
+        .map(|&(_, fitness, ..)| fitness);
+
+    println!(
+        "{:<16}{:>10}{:>10}{:>10}  Sentence",
+        "Strategy", "Fitness", "Gap", "Seconds"
+    );
+    for (strategy, fitness, seconds, sentence) in results {
+        let gap = match optimum {
+            Some(optimum) => format!("{:.4}", optimum - fitness),
+            None => "-".to_string(),
+        };
+        println!(
+            "{:<16}{:>10.4}{:>10}{:>10.2}  {sentence}",
+            strategy.to_string(),
+            fitness,
+            gap,
+            seconds
+        );
+    }
+    if !exhaustive_fits {
+        println!(
+            "Skipped exhaustive, {} words is too many to search, so there is no optimum to compare against",
+            request.word_count()
+        );
+    }
+}
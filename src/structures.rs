@@ -27,7 +27,7 @@ pub const WORD_COUNT_STRUCTURE_FIVE: [[WordType; 5]; 4] = [
         WordType::Noun,
     ],
     [
-        WordType::Noun, //Change to pronoun when implemented
+        WordType::Pronoun,
         WordType::Verb,
         WordType::Determiner,
         WordType::Noun,
@@ -68,3 +68,200 @@ pub const WORD_COUNT_STRUCTURE_EIGHT: [WordType; 8] = [
     WordType::Verb,
     WordType::Adverb,
 ];
+
+/// Questions invert an auxiliary ("Can you swim?") or open with an interrogative word ("Which
+/// dog ate the apple?").
+pub const INTERROGATIVE_STRUCTURES: &[&[WordType]] = &[
+    &[WordType::Verb, WordType::Pronoun, WordType::Verb],
+    &[WordType::Determiner, WordType::Noun, WordType::Verb],
+    &[
+        WordType::Verb,
+        WordType::Determiner,
+        WordType::Noun,
+        WordType::Verb,
+    ],
+    &[
+        WordType::Verb,
+        WordType::Pronoun,
+        WordType::Verb,
+        WordType::Noun,
+    ],
+    &[
+        WordType::Verb,
+        WordType::Determiner,
+        WordType::Noun,
+        WordType::Verb,
+        WordType::Adverb,
+    ],
+    &[
+        WordType::Determiner,
+        WordType::Noun,
+        WordType::Verb,
+        WordType::Determiner,
+        WordType::Noun,
+    ],
+    &[
+        WordType::Verb,
+        WordType::Determiner,
+        WordType::Adjective,
+        WordType::Noun,
+        WordType::Verb,
+        WordType::Adverb,
+    ],
+    &[
+        WordType::Verb,
+        WordType::Pronoun,
+        WordType::Verb,
+        WordType::Determiner,
+        WordType::Noun,
+        WordType::Preposition,
+        WordType::Noun,
+    ],
+    &[
+        WordType::Verb,
+        WordType::Determiner,
+        WordType::Adjective,
+        WordType::Noun,
+        WordType::Verb,
+        WordType::Determiner,
+        WordType::Noun,
+        WordType::Adverb,
+    ],
+];
+
+/// Imperatives have no subject and open with a base form verb ("Eat the apple.").
+pub const IMPERATIVE_STRUCTURES: &[&[WordType]] = &[
+    &[WordType::Verb, WordType::Determiner, WordType::Noun],
+    &[
+        WordType::Verb,
+        WordType::Determiner,
+        WordType::Adjective,
+        WordType::Noun,
+    ],
+    &[
+        WordType::Verb,
+        WordType::Determiner,
+        WordType::Noun,
+        WordType::Adverb,
+    ],
+    &[
+        WordType::Verb,
+        WordType::Determiner,
+        WordType::Noun,
+        WordType::Preposition,
+        WordType::Noun,
+    ],
+    &[
+        WordType::Verb,
+        WordType::Determiner,
+        WordType::Adjective,
+        WordType::Noun,
+        WordType::Preposition,
+        WordType::Noun,
+    ],
+    &[
+        WordType::Verb,
+        WordType::Determiner,
+        WordType::Noun,
+        WordType::Preposition,
+        WordType::Determiner,
+        WordType::Noun,
+        WordType::Adverb,
+    ],
+    &[
+        WordType::Verb,
+        WordType::Determiner,
+        WordType::Adjective,
+        WordType::Noun,
+        WordType::Preposition,
+        WordType::Determiner,
+        WordType::Noun,
+        WordType::Adverb,
+    ],
+];
+
+/// Exclamations open with "what" ("What a tasty apple!").
+pub const EXCLAMATIVE_STRUCTURES: &[&[WordType]] = &[
+    &[WordType::Determiner, WordType::Adjective, WordType::Noun],
+    &[
+        WordType::Determiner,
+        WordType::Determiner,
+        WordType::Adjective,
+        WordType::Noun,
+    ],
+    &[
+        WordType::Determiner,
+        WordType::Adjective,
+        WordType::Noun,
+        WordType::Pronoun,
+        WordType::Verb,
+    ],
+    &[
+        WordType::Determiner,
+        WordType::Determiner,
+        WordType::Adjective,
+        WordType::Noun,
+        WordType::Pronoun,
+        WordType::Verb,
+    ],
+    &[
+        WordType::Determiner,
+        WordType::Determiner,
+        WordType::Adjective,
+        WordType::Noun,
+        WordType::Determiner,
+        WordType::Noun,
+        WordType::Verb,
+    ],
+    &[
+        WordType::Determiner,
+        WordType::Determiner,
+        WordType::Adverb,
+        WordType::Adjective,
+        WordType::Noun,
+        WordType::Determiner,
+        WordType::Noun,
+        WordType::Verb,
+    ],
+];
+
+/// Short independent clauses that compound and complex sentences are built from.
+pub const CLAUSE_STRUCTURES: &[&[WordType]] = &[
+    &[WordType::Noun, WordType::Verb],
+    &[WordType::Pronoun, WordType::Verb],
+    &[WordType::Determiner, WordType::Noun, WordType::Verb],
+    &[WordType::Pronoun, WordType::Verb, WordType::Noun],
+    &[
+        WordType::Determiner,
+        WordType::Noun,
+        WordType::Verb,
+        WordType::Adverb,
+    ],
+    &[
+        WordType::Determiner,
+        WordType::Adjective,
+        WordType::Noun,
+        WordType::Verb,
+    ],
+    &[
+        WordType::Pronoun,
+        WordType::Verb,
+        WordType::Determiner,
+        WordType::Noun,
+    ],
+    &[
+        WordType::Determiner,
+        WordType::Noun,
+        WordType::Verb,
+        WordType::Determiner,
+        WordType::Noun,
+    ],
+    &[
+        WordType::Determiner,
+        WordType::Adjective,
+        WordType::Noun,
+        WordType::Verb,
+        WordType::Determiner,
+        WordType::Noun,
+    ],
+];
@@ -0,0 +1,233 @@
+use std::process;
+
+use crate::{
+    WordType, blocklist,
+    chromosome::{GeneType, genes_written_as, select_form, select_word_of_type},
+    grammar::grammar_penalty,
+    rates::pick,
+    request::Request,
+    tense,
+    words::{collection, weighted},
+};
+
+/// A position in a template, either a word that never changes or a slot to fill.
+#[derive(Debug, Clone)]
+pub enum Slot {
+    Fixed(GeneType),
+    /// A word of a part of speech, only from `words` when the slot names a subtype.
+    Open {
+        word_type: WordType,
+        words: Option<Vec<(f32, u32)>>,
+    },
+}
+
+impl Slot {
+    pub fn word_type(&self) -> WordType {
+        match self {
+            Slot::Fixed(gene) => gene.word_type,
+            Slot::Open { word_type, .. } => *word_type,
+        }
+    }
+
+    /// A word for the slot after the words `before` it, the fixed word itself for a fixed one.
+    pub fn select(&self, request: &Request, before: &[GeneType]) -> GeneType {
+        match self {
+            Slot::Fixed(gene) => *gene,
+            Slot::Open {
+                word_type,
+                words: None,
+            } => select_word_of_type(request, *word_type, before),
+            Slot::Open {
+                word_type,
+                words: Some(words),
+            } => blocklist::select_allowed(|| {
+                let index = pick(words);
+                GeneType {
+                    word_type: *word_type,
+                    index,
+                    form: select_form(
+                        request,
+                        collection(*word_type).word(index).forms(),
+                        tense::is_finite_after(before),
+                    ),
+                }
+            }),
+        }
+    }
+}
+
+/// A partial sentence such as "The {Adjective} {Noun:Collective} {Verb:Intransitive}.",
+/// only the slots in braces are generated.
+#[derive(Debug, Clone)]
+pub struct Template {
+    pub slots: Vec<Slot>,
+}
+
+impl Template {
+    /// Parses a template, exiting if a word isn't in the lexicon or a slot doesn't name a part
+    /// of speech and one of its subtypes. Punctuation is left to the rendering.
+    pub fn parse(template: &str) -> Self {
+        let tokens: Vec<Token> = template
+            .split_whitespace()
+            .map(|token| token.trim_matches(|c: char| matches!(c, '.' | ',' | '!' | '?' | ';')))
+            .filter(|token| !token.is_empty())
+            .map(parse_token)
+            .collect();
+        if tokens.is_empty() {
+            eprintln!("Template {template:?} has no words");
+            process::exit(1);
+        }
+        let slots = (0..tokens.len())
+            .map(|i| match &tokens[i] {
+                Token::Slot(slot) => slot.clone(),
+                Token::Word(readings) => Slot::Fixed(best_reading(readings, &tokens, i)),
+            })
+            .collect();
+        Template { slots }
+    }
+
+    /// The word types of the template, which sentences are scored against.
+    pub fn structure(&self) -> Vec<WordType> {
+        self.slots.iter().map(Slot::word_type).collect()
+    }
+}
+
+/// A parsed template token, a fixed word keeps every reading of it until its neighbours are known.
+enum Token {
+    Slot(Slot),
+    Word(Vec<GeneType>),
+}
+
+impl Token {
+    fn word_types(&self) -> Vec<WordType> {
+        match self {
+            Token::Slot(slot) => vec![slot.word_type()],
+            Token::Word(readings) => readings.iter().map(|g| g.word_type).collect(),
+        }
+    }
+}
+
+/// The reading of a fixed word that fits best between the tokens around it, so "This" before
+/// a noun isn't read as a pronoun. Ties go to the first reading.
+fn best_reading(readings: &[GeneType], tokens: &[Token], position: usize) -> GeneType {
+    let previous = position
+        .checked_sub(1)
+        .map(|i| tokens[i].word_types())
+        .unwrap_or_default();
+    let next = tokens
+        .get(position + 1)
+        .map(Token::word_types)
+        .unwrap_or_default();
+    let cost = |word_type: WordType| {
+        let before = previous
+            .iter()
+            .map(|&p| grammar_penalty(word_type, p))
+            .fold(None, |best: Option<f32>, p| {
+                Some(best.map_or(p, |b| b.min(p)))
+            })
+            .unwrap_or(0.00);
+        let after = next
+            .iter()
+            .map(|&n| grammar_penalty(n, word_type))
+            .fold(None, |best: Option<f32>, p| {
+                Some(best.map_or(p, |b| b.min(p)))
+            })
+            .unwrap_or(0.00);
+        before + after
+    };
+    let mut best = readings[0];
+    for &reading in &readings[1..] {
+        if cost(reading.word_type) < cost(best.word_type) {
+            best = reading;
+        }
+    }
+    best
+}
+
+fn parse_word_type(name: &str) -> Option<WordType> {
+    WordType::ALL
+        .iter()
+        .find(|wt| format!("{wt:?}") == name)
+        .copied()
+}
+
+fn parse_token(token: &str) -> Token {
+    match token.strip_prefix('{').and_then(|t| t.strip_suffix('}')) {
+        Some(slot) => Token::Slot(parse_slot(slot, token)),
+        None => Token::Word(parse_word(token)),
+    }
+}
+
+/// Every reading of a fixed word, only the ones of its part of speech when it's written
+/// like "this:Determiner".
+fn parse_word(token: &str) -> Vec<GeneType> {
+    let (word, word_type) = match token.split_once(':') {
+        Some((word, word_type)) => match parse_word_type(word_type) {
+            Some(word_type) => (word, Some(word_type)),
+            None => {
+                eprintln!("Template word {token} is not a part of speech");
+                process::exit(1);
+            }
+        },
+        None => (token, None),
+    };
+    let readings: Vec<GeneType> = genes_written_as(word)
+        .into_iter()
+        .filter(|g| word_type.is_none_or(|wt| g.word_type == wt))
+        .collect();
+    if readings.is_empty() {
+        match word_type {
+            Some(word_type) => eprintln!("Template word {word:?} is not a {word_type:?}"),
+            None => eprintln!("Template word {word:?} is not in the lexicon"),
+        }
+        process::exit(1);
+    }
+    readings
+}
+
+fn parse_slot(slot: &str, token: &str) -> Slot {
+    let (word_type, subtype) = match slot.split_once(':') {
+        Some((word_type, subtype)) => (word_type, Some(subtype)),
+        None => (slot, None),
+    };
+    let Some(word_type) = parse_word_type(word_type) else {
+        eprintln!("Template slot {token} is not a part of speech");
+        process::exit(1);
+    };
+    let words = subtype.map(
+        |subtype| match collection(word_type).subtype_indices(subtype) {
+            Some(indices) if !indices.is_empty() => weighted(indices, word_type),
+            Some(_) => {
+                eprintln!("Template slot {token} has no words");
+                process::exit(1);
+            }
+            None => {
+                eprintln!("Template slot {token} is not a type of {word_type:?}");
+                process::exit(1);
+            }
+        },
+    );
+    Slot::Open { word_type, words }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ambiguous_fixed_word_fits_its_neighbours() {
+        let template = Template::parse("This {Noun} {Verb:Intransitive}.");
+        assert_ne!(template.slots[0].word_type(), WordType::Pronoun);
+
+        let template = Template::parse("{Verb:Transitive} this.");
+        assert_eq!(template.slots[1].word_type(), WordType::Pronoun);
+    }
+
+    #[test]
+    fn fixed_word_can_name_its_part_of_speech() {
+        let template = Template::parse("This:Pronoun {Verb:Intransitive}.");
+        assert_eq!(template.slots[0].word_type(), WordType::Pronoun);
+        let template = Template::parse("that:Determiner {Noun}");
+        assert_eq!(template.slots[0].word_type(), WordType::Determiner);
+    }
+}
@@ -0,0 +1,133 @@
+use std::str::FromStr;
+
+use serde::Deserialize;
+
+use crate::{WordType, chromosome::GeneType, mood, morphology::Form, words::VERBS};
+
+/// How much a verb phrase is penalised for each of its tense and aspect not matching the request.
+const TENSE_PENALTY: f32 = 0.5;
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Tense {
+    Present,
+    Past,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum Aspect {
+    Simple,
+    Progressive,
+    Perfect,
+}
+
+impl FromStr for Tense {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "present" => Ok(Tense::Present),
+            "past" => Ok(Tense::Past),
+            _ => Err(format!("Unknown tense {s}, expected present or past")),
+        }
+    }
+}
+
+impl FromStr for Aspect {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "simple" => Ok(Aspect::Simple),
+            "progressive" => Ok(Aspect::Progressive),
+            "perfect" => Ok(Aspect::Perfect),
+            _ => Err(format!(
+                "Unknown aspect {s}, expected simple, progressive or perfect"
+            )),
+        }
+    }
+}
+
+impl Tense {
+    /// Whether a verb in this form can be part of a verb phrase in this tense, the finite verb
+    /// decides the tense so gerunds and participles fit either.
+    pub fn allows(self, form: Form) -> bool {
+        match form {
+            Form::Base | Form::Present | Form::ThirdPerson => self == Tense::Present,
+            Form::Past => self == Tense::Past,
+            _ => true,
+        }
+    }
+}
+
+/// Whether a verb after the words `before` it would be the finite one that carries the tense,
+/// it isn't when the last verb before it is an auxiliary or modal ("did eat", "Did I eat?").
+pub fn is_finite_after(before: &[GeneType]) -> bool {
+    !before
+        .iter()
+        .rev()
+        .find(|g| g.word_type == WordType::Verb)
+        .is_some_and(|&g| mood::is_auxiliary(g))
+}
+
+/// The tense of a verb phrase is the form of its first verb.
+fn tense_of(phrase: &[GeneType]) -> Option<Tense> {
+    match phrase[0].form {
+        Form::Base | Form::Present | Form::ThirdPerson => Some(Tense::Present),
+        Form::Past => Some(Tense::Past),
+        _ => None,
+    }
+}
+
+/// "is eating" is progressive and "has eaten" is perfect, "is eaten" is passive so it counts
+/// as simple.
+fn aspect_of(phrase: &[GeneType]) -> Aspect {
+    let [auxiliary, verb, ..] = phrase else {
+        return Aspect::Simple;
+    };
+    let auxiliary_verb = &VERBS.words[auxiliary.index as usize];
+    if !auxiliary_verb.chains_onto(auxiliary.form, verb.form) {
+        return Aspect::Simple;
+    }
+    let chains = &auxiliary_verb.attributes.chains;
+    match verb.form {
+        Form::Gerund => Aspect::Progressive,
+        Form::Participle if !chains.contains(&Form::Gerund) => Aspect::Perfect,
+        _ => Aspect::Simple,
+    }
+}
+
+/// Penalises every verb phrase, a run of verbs, whose tense or aspect isn't the requested one.
+/// A sentence without a verb phrase can't be in any tense so it is penalised as if it were wrong.
+/// In an inverted question the opening auxiliary belongs to the first verb phrase after the
+/// subject ("Has the cat eaten?").
+pub fn penalty(
+    genes: &[GeneType],
+    tense: Option<Tense>,
+    aspect: Option<Aspect>,
+    inverted: bool,
+) -> f32 {
+    let mut phrases: Vec<Vec<GeneType>> = genes
+        .split(|g| g.word_type != WordType::Verb)
+        .filter(|phrase| !phrase.is_empty())
+        .map(|phrase| phrase.to_vec())
+        .collect();
+    if inverted && phrases.len() > 1 && phrases[0].len() == 1 {
+        let auxiliary = phrases.remove(0);
+        phrases[0].insert(0, auxiliary[0]);
+    }
+    if phrases.is_empty() {
+        let requested = tense.is_some() as u8 + aspect.is_some() as u8;
+        return requested as f32 * TENSE_PENALTY;
+    }
+    phrases
+        .iter()
+        .map(|phrase| {
+            let mut penalty = 0.00;
+            if tense.is_some_and(|tense| tense_of(phrase) != Some(tense)) {
+                penalty += TENSE_PENALTY;
+            }
+            if aspect.is_some_and(|aspect| aspect_of(phrase) != aspect) {
+                penalty += TENSE_PENALTY;
+            }
+            penalty
+        })
+        .sum()
+}
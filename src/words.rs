@@ -1,111 +1,245 @@
-use std::{fmt::Debug, process, sync::Arc};
+use std::{borrow::Cow, collections::HashMap, fmt::Debug, fs, process};
 
-use genetica::individual::Generate;
 use lazy_static::lazy_static;
-use serde::Deserialize;
+use serde::{Deserialize, de::DeserializeOwned};
 
-use crate::{CONFIG, WordType};
+use crate::{
+    CONFIG, LEXICON_DIR, WordType,
+    morphology::{self, Form},
+    rates::{available, normalise, pick, thresholds},
+};
 
 pub trait Word: Send + Sync + Debug {
-    fn word_type(&self) -> WordType;
+    /// The lemma as written in the lexicon.
     fn get_word(&self) -> &str;
-    fn grammar(&self, previous_word_type: Option<WordType>) -> f32;
+    /// Every form the word can take.
+    fn forms(&self) -> &'static [Form];
+    fn inflect(&self, form: Form) -> Cow<'_, str>;
+    /// How likely the word is to be picked next to others of its subtype, after the rarity.
+    fn weight(&self) -> f32;
 }
 
-pub trait Collection<T, B>
+/// The attributes of an entry, which decide how it inflects.
+pub trait Attributes: DeserializeOwned + Send + Sync + Debug + 'static {
+    fn forms(&self) -> &'static [Form] {
+        &[Form::Base]
+    }
+    /// The form the lemma is already written in.
+    fn written_form(&self) -> Form {
+        Form::Base
+    }
+    /// The spelling of a form that doesn't follow the regular rules.
+    fn irregular(&self, _form: Form) -> Option<&str> {
+        None
+    }
+}
+
+/// The subtypes of a part of speech, e.g. `NounType`.
+pub trait Subtype: DeserializeOwned + PartialEq + Copy + Debug + Send + Sync + 'static {
+    const WORD_TYPE: WordType;
+    /// Every subtype with its rate from the config.
+    fn rates() -> Vec<(f32, Self)>;
+}
+
+/// Anything that can be stored in a `WeightedCollection`.
+pub trait Entry: DeserializeOwned + Word + 'static {
+    type Subtype: Subtype;
+    fn subtype(&self) -> Self::Subtype;
+    /// The weight as written in the lexicon, before the rarity.
+    fn base_weight(&self) -> f32;
+}
+
+/// A single word in the lexicon, `A` holds the attributes only some parts of speech have.
+#[derive(Deserialize, Debug)]
+pub struct LexiconEntry<S, A = NoAttributes> {
+    pub word: String,
+    pub r#type: S,
+    /// How common the word is relative to others of its subtype.
+    #[serde(default = "default_weight", alias = "frequency")]
+    pub weight: f32,
+    #[serde(flatten)]
+    pub attributes: A,
+}
+
+fn default_weight() -> f32 {
+    1.0
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct NoAttributes {}
+impl Attributes for NoAttributes {}
+
+impl<S, A> Word for LexiconEntry<S, A>
 where
-    T: Word,
-    B: PartialEq,
+    S: Subtype,
+    A: Attributes,
 {
-    fn select(&self) -> Arc<dyn Word + '_>;
-    fn find_of_type(&self, r#type: &B) -> Option<&T>;
-    fn calculate_thresholds(&mut self);
+    fn get_word(&self) -> &str {
+        &self.word
+    }
+    fn forms(&self) -> &'static [Form] {
+        self.attributes.forms()
+    }
+    fn inflect(&self, form: Form) -> Cow<'_, str> {
+        if form == self.attributes.written_form() {
+            return Cow::Borrowed(&self.word);
+        }
+        match self.attributes.irregular(form) {
+            Some(irregular) => Cow::Borrowed(irregular),
+            None => morphology::inflect(&self.word, form),
+        }
+    }
+    fn weight(&self) -> f32 {
+        self.weight.powf(1.0 - CONFIG.rarity)
+    }
+}
+
+impl<S, A> Entry for LexiconEntry<S, A>
+where
+    S: Subtype,
+    A: Attributes,
+{
+    type Subtype = S;
+    fn subtype(&self) -> S {
+        self.r#type
+    }
+    fn base_weight(&self) -> f32 {
+        self.weight
+    }
 }
 
-#[derive(Deserialize, PartialEq, Debug, Clone)]
+#[derive(Deserialize, PartialEq, Debug, Clone, Copy)]
 pub enum Number {
     Singular,
     Plural,
 }
-#[derive(Deserialize, PartialEq, Debug, Clone)]
+/// What kind of thing a noun or pronoun refers to, for verbs to expect of their arguments.
+#[derive(Deserialize, PartialEq, Debug, Clone, Copy)]
+pub enum Feature {
+    Animate,
+    Human,
+    Food,
+    Place,
+    Artifact,
+    Time,
+}
+impl Feature {
+    /// Whether something with these features can be this, every human is animate.
+    pub fn satisfied_by(self, features: &[Feature]) -> bool {
+        features
+            .iter()
+            .any(|&f| f == self || (self == Feature::Animate && f == Feature::Human))
+    }
+}
+#[derive(Deserialize, PartialEq, Debug, Clone, Copy)]
 pub enum Tangibility {
     Concrete,
     Abstract,
 }
-#[derive(Deserialize, PartialEq, Debug, Clone)]
+#[derive(Deserialize, PartialEq, Debug, Clone, Copy)]
 pub enum Countability {
     Countable,
     Uncountable,
     Both,
 }
+#[derive(Deserialize, PartialEq, Debug, Clone, Copy)]
+pub enum Person {
+    First,
+    Second,
+    Third,
+}
+/// `Both` is for pronouns like "you" and "it" that work as a subject and an object.
+#[derive(Deserialize, PartialEq, Debug, Clone, Copy)]
+pub enum Case {
+    Subject,
+    Object,
+    Possessive,
+    Both,
+}
 
 // Noun
-#[derive(Deserialize, PartialEq, Debug, Clone)]
+#[derive(Deserialize, PartialEq, Debug, Clone, Copy)]
 pub enum NounType {
     Common,
     Proper,
     Collective,
 }
+impl Subtype for NounType {
+    const WORD_TYPE: WordType = WordType::Noun;
+    fn rates() -> Vec<(f32, Self)> {
+        let rates = CONFIG.noun_rates.type_rates;
+        vec![
+            (rates.common, NounType::Common),
+            (rates.proper, NounType::Proper),
+            (rates.collective, NounType::Collective),
+        ]
+    }
+}
 
 #[derive(Deserialize, Debug)]
-pub struct Noun {
-    pub word: String,
-    pub r#type: NounType,
-    pub number: Number,
+pub struct NounAttributes {
     pub tangibility: Tangibility,
     pub countability: Countability,
-}
-
-// Impl Word for both Noun and &Noun seems stupid but its the least complicated way
-impl Word for Noun {
-    fn word_type(&self) -> WordType
-    where
-        Self: Sized,
-    {
-        WordType::Noun
-    }
-    fn get_word(&self) -> &str {
-        &self.word
+    pub plural: Option<String>,
+    #[serde(default)]
+    pub features: Vec<Feature>,
+}
+impl Attributes for NounAttributes {
+    fn forms(&self) -> &'static [Form] {
+        match self.countability {
+            Countability::Uncountable => &[Form::Base],
+            Countability::Countable | Countability::Both => &Form::NOUN,
+        }
     }
-    fn grammar(&self, previous_word_type: Option<WordType>) -> f32 {
-        let mut grammar_rating: f32 = 0.00;
-        if let Some(previous_word_type) = previous_word_type {
-            match previous_word_type {
-                WordType::Noun => grammar_rating -= 0.4,
-                WordType::Verb => grammar_rating -= 0.2,
-                WordType::Adverb => grammar_rating -= 0.3,
-                _ => {}
-            }
-        };
-        grammar_rating
+    fn irregular(&self, form: Form) -> Option<&str> {
+        match form {
+            Form::Plural => self.plural.as_deref(),
+            _ => None,
+        }
     }
 }
-impl Word for &Noun {
-    fn word_type(&self) -> WordType
-    where
-        Self: Sized,
-    {
-        WordType::Noun
-    }
-    fn get_word(&self) -> &str {
-        &self.word
-    }
-    fn grammar(&self, previous_word_type: Option<WordType>) -> f32 {
-        let mut grammar_rating: f32 = 0.00;
-        if let Some(previous_word_type) = previous_word_type {
-            match previous_word_type {
-                WordType::Noun => grammar_rating -= 0.4,
-                WordType::Verb => grammar_rating -= 0.2,
-                WordType::Adverb => grammar_rating -= 0.3,
-                _ => {}
-            }
-        };
-        grammar_rating
+pub type Noun = LexiconEntry<NounType, NounAttributes>;
+
+// Pronoun
+#[derive(Deserialize, PartialEq, Debug, Clone, Copy)]
+pub enum PronounType {
+    Personal,
+    Possessive,
+    Reflexive,
+    Relative,
+    Demonstrative,
+    Interrogative,
+    Indefinite,
+}
+impl Subtype for PronounType {
+    const WORD_TYPE: WordType = WordType::Pronoun;
+    fn rates() -> Vec<(f32, Self)> {
+        let rates = CONFIG.pronoun_type_rates;
+        vec![
+            (rates.personal, PronounType::Personal),
+            (rates.possessive, PronounType::Possessive),
+            (rates.reflexive, PronounType::Reflexive),
+            (rates.relative, PronounType::Relative),
+            (rates.demonstrative, PronounType::Demonstrative),
+            (rates.interrogative, PronounType::Interrogative),
+            (rates.indefinite, PronounType::Indefinite),
+        ]
     }
 }
 
+#[derive(Deserialize, Debug)]
+pub struct PronounAttributes {
+    pub person: Person,
+    pub number: Number,
+    pub case: Case,
+    #[serde(default)]
+    pub features: Vec<Feature>,
+}
+impl Attributes for PronounAttributes {}
+pub type Pronoun = LexiconEntry<PronounType, PronounAttributes>;
+
 // Verb
-#[derive(Deserialize, PartialEq, Debug, Clone)]
+#[derive(Deserialize, PartialEq, Debug, Clone, Copy)]
 pub enum VerbType {
     Action,
     Transitive,
@@ -116,65 +250,94 @@ pub enum VerbType {
     Regular,
     Irregular,
 }
-
-#[derive(Deserialize, Debug)]
-pub struct Verb {
-    pub word: String,
-    pub r#type: VerbType,
-}
-
-impl Word for Verb {
-    fn word_type(&self) -> WordType
-    where
-        Self: Sized,
-    {
-        WordType::Verb
+impl Subtype for VerbType {
+    const WORD_TYPE: WordType = WordType::Verb;
+    fn rates() -> Vec<(f32, Self)> {
+        let rates = CONFIG.verb_type_rates;
+        vec![
+            (rates.intransitive, VerbType::Intransitive),
+            (rates.action, VerbType::Action),
+            (rates.transitive, VerbType::Transitive),
+            (rates.linking, VerbType::Linking),
+            (rates.modal, VerbType::Modal),
+            (rates.irregular, VerbType::Irregular),
+            (rates.regular, VerbType::Regular),
+            (rates.auxiliary, VerbType::Auxiliary),
+        ]
+    }
+}
+/// `form` is set on words that are already inflected ("is", "been") or never inflect (modals),
+/// `chains` on an auxiliary or modal lists the forms it can be followed by ("has eaten", "can run"),
+/// and `chains_from` the forms of its own that can, when only some of them can ("did eat" but
+/// not "doing eat").
+#[derive(Deserialize, Debug, Clone)]
+pub struct VerbAttributes {
+    pub form: Option<Form>,
+    #[serde(default)]
+    pub chains: Vec<Form>,
+    pub chains_from: Option<Vec<Form>>,
+    pub third_person: Option<String>,
+    pub past: Option<String>,
+    pub gerund: Option<String>,
+    pub participle: Option<String>,
+    /// The feature the verb expects of its subject ("eat" wants something animate).
+    pub subject: Option<Feature>,
+    /// The feature the verb expects of its object ("eat" wants food).
+    pub object: Option<Feature>,
+}
+impl Attributes for VerbAttributes {
+    fn forms(&self) -> &'static [Form] {
+        match self.form {
+            Some(form) => form.only(),
+            None => &Form::VERB,
+        }
     }
-    fn get_word(&self) -> &str {
-        &self.word
+    fn written_form(&self) -> Form {
+        self.form.unwrap_or_default()
     }
-    fn grammar(&self, previous_word_type: Option<WordType>) -> f32 {
-        let mut grammar_rating: f32 = 0.00;
-        if let Some(previous_word_type) = previous_word_type {
-            match previous_word_type {
-                WordType::Verb => grammar_rating -= 0.4,
-                WordType::Determiner => grammar_rating -= 0.3,
-                WordType::Preposition => grammar_rating -= 0.4,
-                WordType::Adjective => grammar_rating -= 0.2,
-                _ => {}
-            }
-        };
-        grammar_rating
+    fn irregular(&self, form: Form) -> Option<&str> {
+        match form {
+            Form::ThirdPerson => self.third_person.as_deref(),
+            Form::Past => self.past.as_deref(),
+            Form::Gerund => self.gerund.as_deref(),
+            Form::Participle => self.participle.as_deref(),
+            _ => None,
+        }
     }
 }
+impl VerbAttributes {
+    fn has_irregular_forms(&self) -> bool {
+        self.third_person.is_some()
+            || self.past.is_some()
+            || self.gerund.is_some()
+            || self.participle.is_some()
+    }
+}
+pub type Verb = LexiconEntry<VerbType, VerbAttributes>;
 
-impl Word for &Verb {
-    fn word_type(&self) -> WordType
-    where
-        Self: Sized,
-    {
-        WordType::Verb
+impl Verb {
+    /// Modals, and auxiliaries that never inflect like them, don't agree with their subject.
+    pub fn is_modal(&self) -> bool {
+        self.r#type == VerbType::Modal
+            || (self.r#type == VerbType::Auxiliary && self.attributes.form == Some(Form::Base))
     }
-    fn get_word(&self) -> &str {
-        &self.word
+    /// Whether the verb in `form` takes another verb after it at all.
+    pub fn chains(&self, form: Form) -> bool {
+        !self.attributes.chains.is_empty()
+            && self
+                .attributes
+                .chains_from
+                .as_ref()
+                .is_none_or(|forms| forms.contains(&form))
     }
-    fn grammar(&self, previous_word_type: Option<WordType>) -> f32 {
-        let mut grammar_rating: f32 = 0.00;
-        if let Some(previous_word_type) = previous_word_type {
-            match previous_word_type {
-                WordType::Verb => grammar_rating -= 0.4,
-                WordType::Determiner => grammar_rating -= 0.3,
-                WordType::Preposition => grammar_rating -= 0.4,
-                WordType::Adjective => grammar_rating -= 0.2,
-                _ => {}
-            }
-        };
-        grammar_rating
+    /// Whether the verb in `form` can be followed by a verb in `next` ("has eaten", "can run").
+    pub fn chains_onto(&self, form: Form, next: Form) -> bool {
+        self.chains(form) && self.attributes.chains.contains(&next)
     }
 }
 
 // Adverb
-#[derive(Deserialize, PartialEq, Debug, Clone)]
+#[derive(Deserialize, PartialEq, Debug, Clone, Copy)]
 pub enum AdverbType {
     Time,
     Frequency,
@@ -183,65 +346,24 @@ pub enum AdverbType {
     Manner,
     Conjunctive,
 }
-
-#[derive(Deserialize, Debug)]
-pub struct Adverb {
-    pub word: String,
-    pub r#type: AdverbType,
-}
-
-impl Word for Adverb {
-    fn word_type(&self) -> WordType
-    where
-        Self: Sized,
-    {
-        WordType::Adverb
-    }
-    fn get_word(&self) -> &str {
-        &self.word
-    }
-    fn grammar(&self, previous_word_type: Option<WordType>) -> f32 {
-        let mut grammar_rating: f32 = 0.00;
-        if let Some(previous_word_type) = previous_word_type {
-            match previous_word_type {
-                WordType::Noun => grammar_rating -= 0.5,
-                WordType::Adverb => grammar_rating -= 0.2,
-                WordType::Determiner => grammar_rating -= 0.5,
-                WordType::Preposition => grammar_rating -= 0.4,
-                _ => {}
-            }
-        };
-        grammar_rating
-    }
-}
-
-impl Word for &Adverb {
-    fn word_type(&self) -> WordType
-    where
-        Self: Sized,
-    {
-        WordType::Adverb
-    }
-    fn get_word(&self) -> &str {
-        &self.word
-    }
-    fn grammar(&self, previous_word_type: Option<WordType>) -> f32 {
-        let mut grammar_rating: f32 = 0.00;
-        if let Some(previous_word_type) = previous_word_type {
-            match previous_word_type {
-                WordType::Noun => grammar_rating -= 0.5,
-                WordType::Adverb => grammar_rating -= 0.2,
-                WordType::Determiner => grammar_rating -= 0.5,
-                WordType::Preposition => grammar_rating -= 0.4,
-                _ => {}
-            }
-        };
-        grammar_rating
+impl Subtype for AdverbType {
+    const WORD_TYPE: WordType = WordType::Adverb;
+    fn rates() -> Vec<(f32, Self)> {
+        let rates = CONFIG.adverb_type_rates;
+        vec![
+            (rates.frequency, AdverbType::Frequency),
+            (rates.degree, AdverbType::Degree),
+            (rates.time, AdverbType::Time),
+            (rates.manner, AdverbType::Manner),
+            (rates.conjunctive, AdverbType::Conjunctive),
+            (rates.place, AdverbType::Place),
+        ]
     }
 }
+pub type Adverb = LexiconEntry<AdverbType>;
 
 // Adjective
-#[derive(Deserialize, PartialEq, Debug, Clone)]
+#[derive(Deserialize, PartialEq, Debug, Clone, Copy)]
 pub enum AdjectiveType {
     Interrogative,
     Distributive,
@@ -252,130 +374,72 @@ pub enum AdjectiveType {
     Quantitative,
     Demonstrative,
 }
-
-#[derive(Deserialize, Debug)]
-pub struct Adjective {
-    pub word: String,
-    pub r#type: AdjectiveType,
-}
-
-impl Word for Adjective {
-    fn word_type(&self) -> WordType
-    where
-        Self: Sized,
-    {
-        WordType::Adjective
-    }
-    fn get_word(&self) -> &str {
-        &self.word
-    }
-    fn grammar(&self, previous_word_type: Option<WordType>) -> f32 {
-        let mut grammar_rating: f32 = 0.00;
-        if let Some(previous_word_type) = previous_word_type {
-            match previous_word_type {
-                WordType::Noun => grammar_rating -= 0.3,
-                WordType::Verb => grammar_rating -= 0.2,
-                WordType::Preposition => grammar_rating -= 0.4,
-                _ => {}
-            }
-        };
-        grammar_rating
+impl Subtype for AdjectiveType {
+    const WORD_TYPE: WordType = WordType::Adjective;
+    fn rates() -> Vec<(f32, Self)> {
+        let rates = CONFIG.adjective_type_rates;
+        vec![
+            (rates.numeral, AdjectiveType::Numeral),
+            (rates.interrogative, AdjectiveType::Interrogative),
+            (rates.distributive, AdjectiveType::Distributive),
+            (rates.descriptive, AdjectiveType::Descriptive),
+            (rates.possessive, AdjectiveType::Possessive),
+            (rates.demonstrative, AdjectiveType::Demonstrative),
+            (rates.quantitative, AdjectiveType::Quantitative),
+            (rates.proper, AdjectiveType::Proper),
+        ]
     }
 }
-
-impl Word for &Adjective {
-    fn word_type(&self) -> WordType
-    where
-        Self: Sized,
-    {
-        WordType::Adjective
-    }
-    fn get_word(&self) -> &str {
-        &self.word
+/// Only gradable adjectives have comparatives and superlatives ("tastier", "tastiest").
+#[derive(Deserialize, Debug)]
+pub struct AdjectiveAttributes {
+    #[serde(default)]
+    pub gradable: bool,
+    pub comparative: Option<String>,
+    pub superlative: Option<String>,
+}
+impl Attributes for AdjectiveAttributes {
+    fn forms(&self) -> &'static [Form] {
+        if self.gradable {
+            &Form::ADJECTIVE
+        } else {
+            &[Form::Base]
+        }
     }
-    fn grammar(&self, previous_word_type: Option<WordType>) -> f32 {
-        let mut grammar_rating: f32 = 0.00;
-        if let Some(previous_word_type) = previous_word_type {
-            match previous_word_type {
-                WordType::Noun => grammar_rating -= 0.3,
-                WordType::Verb => grammar_rating -= 0.2,
-                WordType::Preposition => grammar_rating -= 0.4,
-                _ => {}
-            }
-        };
-        grammar_rating
+    fn irregular(&self, form: Form) -> Option<&str> {
+        match form {
+            Form::Comparative => self.comparative.as_deref(),
+            Form::Superlative => self.superlative.as_deref(),
+            _ => None,
+        }
     }
 }
+pub type Adjective = LexiconEntry<AdjectiveType, AdjectiveAttributes>;
 
 // Preposition
-#[derive(Deserialize, PartialEq, Debug, Clone)]
+#[derive(Deserialize, PartialEq, Debug, Clone, Copy)]
 pub enum PrepositionType {
     Place,
     Time,
     Movement,
     Purpose,
 }
-
-#[derive(Deserialize, Debug)]
-pub struct Preposition {
-    pub word: String,
-    pub r#type: PrepositionType,
-}
-
-impl Word for Preposition {
-    fn word_type(&self) -> WordType
-    where
-        Self: Sized,
-    {
-        WordType::Preposition
-    }
-    fn get_word(&self) -> &str {
-        &self.word
-    }
-    fn grammar(&self, previous_word_type: Option<WordType>) -> f32 {
-        let mut grammar_rating: f32 = 0.00;
-        if let Some(previous_word_type) = previous_word_type {
-            match previous_word_type {
-                WordType::Verb => grammar_rating -= 0.1,
-                WordType::Adverb => grammar_rating -= 0.3,
-                WordType::Determiner => grammar_rating -= 0.5,
-                WordType::Preposition => grammar_rating -= 0.4,
-                WordType::Adjective => grammar_rating -= 0.2,
-                _ => {}
-            }
-        };
-        grammar_rating
-    }
-}
-
-impl Word for &Preposition {
-    fn word_type(&self) -> WordType
-    where
-        Self: Sized,
-    {
-        WordType::Preposition
-    }
-    fn get_word(&self) -> &str {
-        &self.word
-    }
-    fn grammar(&self, previous_word_type: Option<WordType>) -> f32 {
-        let mut grammar_rating: f32 = 0.00;
-        if let Some(previous_word_type) = previous_word_type {
-            match previous_word_type {
-                WordType::Verb => grammar_rating -= 0.1,
-                WordType::Adverb => grammar_rating -= 0.3,
-                WordType::Determiner => grammar_rating -= 0.5,
-                WordType::Preposition => grammar_rating -= 0.4,
-                WordType::Adjective => grammar_rating -= 0.2,
-                _ => {}
-            }
-        };
-        grammar_rating
+impl Subtype for PrepositionType {
+    const WORD_TYPE: WordType = WordType::Preposition;
+    fn rates() -> Vec<(f32, Self)> {
+        let rates = CONFIG.preposition_type_rates;
+        vec![
+            (rates.place, PrepositionType::Place),
+            (rates.time, PrepositionType::Time),
+            (rates.movement, PrepositionType::Movement),
+            (rates.purpose, PrepositionType::Purpose),
+        ]
     }
 }
+pub type Preposition = LexiconEntry<PrepositionType>;
 
 // Determiner
-#[derive(Deserialize, PartialEq, Clone, Debug)]
+#[derive(Deserialize, PartialEq, Clone, Copy, Debug)]
 pub enum DeterminerType {
     Article,
     Demonstrative,
@@ -386,527 +450,420 @@ pub enum DeterminerType {
     Relative,
     Negative,
 }
-
-#[derive(Deserialize, Debug)]
-pub struct Determiner {
-    pub word: String,
-    pub r#type: DeterminerType,
-}
-
-impl Word for Determiner {
-    fn word_type(&self) -> WordType
-    where
-        Self: Sized,
-    {
-        WordType::Determiner
-    }
-    fn get_word(&self) -> &str {
-        &self.word
-    }
-    fn grammar(&self, previous_word_type: Option<WordType>) -> f32 {
-        let mut grammar_rating: f32 = 0.00;
-        if let Some(previous_word_type) = previous_word_type {
-            match previous_word_type {
-                WordType::Noun => grammar_rating -= 0.5,
-                WordType::Verb => grammar_rating -= 0.4,
-                WordType::Adverb => grammar_rating -= 0.3,
-                WordType::Determiner => grammar_rating -= 0.6,
-                WordType::Adjective => grammar_rating -= 0.3,
-                _ => {}
-            }
-        };
-        grammar_rating
-    }
-}
-
-impl Word for &Determiner {
-    fn word_type(&self) -> WordType
-    where
-        Self: Sized,
-    {
-        WordType::Determiner
-    }
-    fn get_word(&self) -> &str {
-        &self.word
-    }
-    fn grammar(&self, previous_word_type: Option<WordType>) -> f32 {
-        let mut grammar_rating: f32 = 0.00;
-        if let Some(previous_word_type) = previous_word_type {
-            match previous_word_type {
-                WordType::Noun => grammar_rating -= 0.5,
-                WordType::Verb => grammar_rating -= 0.4,
-                WordType::Adverb => grammar_rating -= 0.3,
-                WordType::Determiner => grammar_rating -= 0.6,
-                WordType::Adjective => grammar_rating -= 0.3,
-                _ => {}
-            }
-        };
-        grammar_rating
+impl Subtype for DeterminerType {
+    const WORD_TYPE: WordType = WordType::Determiner;
+    fn rates() -> Vec<(f32, Self)> {
+        let rates = CONFIG.determiner_type_rates;
+        vec![
+            (rates.distributive, DeterminerType::Distributive),
+            (rates.article, DeterminerType::Article),
+            (rates.demonstrative, DeterminerType::Demonstrative),
+            (rates.possessive, DeterminerType::Possessive),
+            (rates.quantifier, DeterminerType::Quantifier),
+            (rates.negative, DeterminerType::Negative),
+            (rates.relative, DeterminerType::Relative),
+            (rates.interrogative, DeterminerType::Interrogative),
+        ]
     }
 }
+pub type Determiner = LexiconEntry<DeterminerType>;
 
 // Conjunction
-#[derive(Deserialize, PartialEq, Clone, Debug)]
+#[derive(Deserialize, PartialEq, Clone, Copy, Debug)]
 pub enum ConjunctionType {
     Coordinating,
     Subordinating,
     Correlative,
 }
-#[derive(Deserialize, Debug)]
-pub struct Conjunction {
-    pub word: String,
-    pub r#type: ConjunctionType,
-    pub pair: Option<String>,
-}
-
-impl Word for Conjunction {
-    fn word_type(&self) -> WordType
-    where
-        Self: Sized,
-    {
-        WordType::Conjunction
-    }
-    fn get_word(&self) -> &str {
-        &self.word
-    }
-    fn grammar(&self, previous_word_type: Option<WordType>) -> f32 {
-        let mut grammar_rating: f32 = 0.00;
-        if let Some(previous_word_type) = previous_word_type {
-            match previous_word_type {
-                WordType::Adverb => grammar_rating -= 0.2,
-                WordType::Determiner => grammar_rating -= 0.5,
-                WordType::Preposition => grammar_rating -= 0.3,
-                WordType::Conjunction => grammar_rating -= 0.4,
-                _ => {}
-            }
-        };
-        grammar_rating
+impl Subtype for ConjunctionType {
+    const WORD_TYPE: WordType = WordType::Conjunction;
+    fn rates() -> Vec<(f32, Self)> {
+        let rates = CONFIG.conjunction_type_rates;
+        vec![
+            (rates.coordinating, ConjunctionType::Coordinating),
+            (rates.subordinating, ConjunctionType::Subordinating),
+            (rates.correlative, ConjunctionType::Correlative),
+        ]
     }
 }
 
-impl Word for &Conjunction {
-    fn word_type(&self) -> WordType
-    where
-        Self: Sized,
-    {
-        WordType::Conjunction
-    }
-    fn get_word(&self) -> &str {
-        &self.word
-    }
-    fn grammar(&self, previous_word_type: Option<WordType>) -> f32 {
-        let mut grammar_rating: f32 = 0.00;
-        if let Some(previous_word_type) = previous_word_type {
-            match previous_word_type {
-                WordType::Adverb => grammar_rating -= 0.2,
-                WordType::Determiner => grammar_rating -= 0.5,
-                WordType::Preposition => grammar_rating -= 0.3,
-                WordType::Conjunction => grammar_rating -= 0.4,
-                _ => {}
-            }
-        };
-        grammar_rating
-    }
+#[derive(Deserialize, Debug)]
+pub struct ConjunctionAttributes {
+    pub pair: Option<String>,
 }
-
-// Collection of nouns
-#[derive(Deserialize)]
-pub struct Nouns {
-    pub words: Vec<Noun>,
-    #[serde(skip)]
-    pub thresholds: Option<[(f32, NounType); 3]>,
-}
-impl Collection<Noun, NounType> for Nouns {
-    fn select(&self) -> Arc<dyn Word + '_> {
-        let thresholds = self.thresholds.as_ref().unwrap();
-        loop {
-            let random_f32: f32 = rand::random_range(0.00..1.00);
-            for &(threshold, ref noun_type) in thresholds {
-                if random_f32 <= threshold {
-                    if let Some(noun) = self.find_of_type(&noun_type) {
-                        return Arc::new(noun);
-                    } else {
-                        eprintln!("No nouns of type {:?}", &noun_type);
-                    };
-                }
-            }
-        }
-    }
-    fn find_of_type(&self, r#type: &NounType) -> Option<&Noun> {
-        loop {
-            let word = &self.words[rand::random_range(0..self.words.len())];
-            if &word.r#type == r#type {
-                return Some(word);
-            };
-        }
-    }
-    fn calculate_thresholds(&mut self) {
-        let rates = CONFIG.noun_rates.type_rates;
-        #[rustfmt::skip]
-        let thresholds: [(f32, NounType); 3] = [
-            (rates.common, NounType::Common),
-            (rates.common + rates.proper, NounType::Proper),
-            (rates.common + rates.proper + rates.collective, NounType::Collective)
-        ];
-        self.thresholds = Some(thresholds)
-    }
+impl Attributes for ConjunctionAttributes {}
+pub type Conjunction = LexiconEntry<ConjunctionType, ConjunctionAttributes>;
+
+// Interjection
+#[derive(Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum InterjectionType {
+    Emotion,
+    Greeting,
+    Agreement,
+    Hesitation,
+}
+impl Subtype for InterjectionType {
+    const WORD_TYPE: WordType = WordType::Interjection;
+    fn rates() -> Vec<(f32, Self)> {
+        let rates = CONFIG.interjection_type_rates;
+        vec![
+            (rates.emotion, InterjectionType::Emotion),
+            (rates.greeting, InterjectionType::Greeting),
+            (rates.agreement, InterjectionType::Agreement),
+            (rates.hesitation, InterjectionType::Hesitation),
+        ]
+    }
+}
+pub type Interjection = LexiconEntry<InterjectionType>;
+
+// Numeral
+#[derive(Deserialize, PartialEq, Clone, Copy, Debug)]
+pub enum NumeralType {
+    Cardinal,
+    Ordinal,
+}
+impl Subtype for NumeralType {
+    const WORD_TYPE: WordType = WordType::Numeral;
+    fn rates() -> Vec<(f32, Self)> {
+        let rates = CONFIG.numeral_type_rates;
+        vec![
+            (rates.cardinal, NumeralType::Cardinal),
+            (rates.ordinal, NumeralType::Ordinal),
+        ]
+    }
+}
+
+/// Cardinals have the number of the noun they count, ordinals go with either.
+#[derive(Deserialize, Debug)]
+pub struct NumeralAttributes {
+    pub number: Option<Number>,
 }
+impl Attributes for NumeralAttributes {}
+pub type Numeral = LexiconEntry<NumeralType, NumeralAttributes>;
 
-// Collection of verbs
+/// The words of one part of speech, selected by the subtype rates in the config.
 #[derive(Deserialize)]
-pub struct Verbs {
-    pub words: Vec<Verb>,
+#[serde(bound = "")]
+pub struct WeightedCollection<T: Entry> {
+    pub words: Vec<T>,
+    /// Thresholds for picking one of the groups in `word_thresholds` by its rate.
     #[serde(skip)]
-    pub thresholds: Option<[(f32, VerbType); 8]>,
-}
-impl Collection<Verb, VerbType> for Verbs {
-    fn select(&self) -> Arc<dyn Word + '_> {
-        let thresholds = self.thresholds.as_ref().unwrap();
-        loop {
-            let random_f32: f32 = rand::random_range(0.00..1.00);
-            for &(threshold, ref verb_type) in thresholds {
-                if random_f32 <= threshold {
-                    if let Some(verb) = self.find_of_type(&verb_type) {
-                        return Arc::new(verb);
-                    } else {
-                        eprintln!("No verbs of type {:?}", verb_type);
-                    }
-                }
-            }
+    pub thresholds: Vec<(f32, usize)>,
+    #[serde(skip)]
+    pub indices: Vec<(T::Subtype, Vec<u32>)>,
+    /// Thresholds for picking a word of each group by weight, a group is a subtype unless the
+    /// part of speech splits its words further.
+    #[serde(skip)]
+    pub word_thresholds: Vec<Vec<(f32, u32)>>,
+}
+
+impl<T: Entry> WeightedCollection<T> {
+    /// Thresholds for picking one of the words by weight.
+    fn weighted(&self, indices: &[u32]) -> Vec<(f32, u32)> {
+        thresholds(
+            indices
+                .iter()
+                .map(|&i| (self.words[i as usize].weight(), i))
+                .collect(),
+        )
+    }
+    /// Groups the words by subtype, picked from by the subtype rates.
+    pub fn calculate_thresholds(&mut self) {
+        let rates = T::Subtype::rates();
+        self.indices = rates
+            .iter()
+            .map(|&(_, subtype)| {
+                let indices = (0..self.words.len() as u32)
+                    .filter(|&i| self.words[i as usize].subtype() == subtype)
+                    .collect();
+                (subtype, indices)
+            })
+            .collect();
+        let groups = available(
+            rates
+                .iter()
+                .zip(&self.indices)
+                .map(|(&(rate, _), (_, indices))| (rate, indices))
+                .collect(),
+            |indices| !indices.is_empty(),
+        );
+        if groups.is_empty() {
+            eprintln!(
+                "No {:?}s have a type with a rate above 0",
+                T::Subtype::WORD_TYPE
+            );
+            process::exit(1);
         }
-    }
-    fn find_of_type(&self, r#type: &VerbType) -> Option<&Verb> {
-        loop {
-            let word = &self.words[rand::random_range(0..self.words.len())];
-            if &word.r#type == r#type {
-                return Some(word);
-            };
+        let groups = groups
+            .into_iter()
+            .map(|(rate, indices)| (rate, self.weighted(indices)))
+            .collect();
+        self.set_groups(groups);
+    }
+    /// Picks from these groups of words by the rate of each instead of one group per subtype.
+    fn set_groups(&mut self, groups: Vec<(f32, Vec<(f32, u32)>)>) {
+        self.thresholds = thresholds(
+            groups
+                .iter()
+                .enumerate()
+                .map(|(group, &(rate, _))| (rate, group))
+                .collect(),
+        );
+        self.word_thresholds = groups.into_iter().map(|(_, words)| words).collect();
+    }
+}
+
+/// The word list of any part of speech, so code that works on all of them can get one with
+/// `collection` instead of matching on the word type.
+pub trait Lexicon: Send + Sync {
+    fn word(&self, index: u32) -> &dyn Word;
+    /// How many entries the word list has.
+    fn size(&self) -> u32;
+    /// Selects a word by the subtype rates in the config and the word weights.
+    fn select(&self) -> u32;
+    /// The names of every subtype, in the order of their rates.
+    fn subtype_names(&self) -> Vec<String>;
+    /// The indices of every word whose subtype is named `name`, e.g. "Collective".
+    fn subtype_indices(&self, name: &str) -> Option<&[u32]>;
+    /// The name of a word's subtype, e.g. "Collective".
+    fn subtype_name(&self, index: u32) -> String;
+}
+
+impl<T: Entry> Lexicon for WeightedCollection<T> {
+    fn word(&self, index: u32) -> &dyn Word {
+        &self.words[index as usize]
+    }
+    fn size(&self) -> u32 {
+        self.words.len() as u32
+    }
+    fn select(&self) -> u32 {
+        pick(&self.word_thresholds[pick(&self.thresholds)])
+    }
+    fn subtype_names(&self) -> Vec<String> {
+        self.indices.iter().map(|(s, _)| format!("{s:?}")).collect()
+    }
+    fn subtype_indices(&self, name: &str) -> Option<&[u32]> {
+        self.indices
+            .iter()
+            .find(|(s, _)| format!("{s:?}") == name)
+            .map(|(_, indices)| indices.as_slice())
+    }
+    fn subtype_name(&self, index: u32) -> String {
+        format!("{:?}", self.words[index as usize].subtype())
+    }
+}
+
+/// Loads a word list, from `LEXICON_DIR` when it's set and the built in one otherwise.
+fn load<T: Entry>(file_name: &str, built_in: &str) -> WeightedCollection<T> {
+    let content = match LEXICON_DIR.get() {
+        Some(dir) => match fs::read_to_string(dir.join(file_name)) {
+            Ok(content) => Cow::Owned(content),
+            Err(e) => {
+                eprintln!("Error reading {file_name}: {e}");
+                process::exit(1);
+            }
+        },
+        None => Cow::Borrowed(built_in),
+    };
+    let mut collection: WeightedCollection<T> = match toml::from_str(&content) {
+        Ok(c) => c,
+        Err(e) => {
+            eprintln!("Error with {file_name}: {e:#}");
+            process::exit(1);
         }
-    }
-    fn calculate_thresholds(&mut self) {
-        let rates = CONFIG.verb_type_rates;
-        #[rustfmt::skip]
-        let thresholds: [(f32, VerbType); 8] = [
-            (rates.intransitive, VerbType::Intransitive),
-            (rates.intransitive + rates.action, VerbType::Action),
-            (rates.intransitive + rates.action + rates.transitive, VerbType::Transitive),
-            (rates.intransitive + rates.action + rates.transitive + rates.linking, VerbType::Linking),
-            (rates.intransitive + rates.action + rates.transitive + rates.linking + rates.modal, VerbType::Modal),
-            (rates.intransitive + rates.action + rates.transitive + rates.linking + rates.modal + rates.irregular, VerbType::Irregular),
-            (rates.intransitive + rates.action + rates.transitive + rates.linking + rates.modal + rates.irregular + rates.regular, VerbType::Regular),
-            (rates.intransitive + rates.action + rates.transitive + rates.linking + rates.modal + rates.irregular + rates.regular + rates.auxiliary, VerbType::Auxiliary),
-        ];
-        self.thresholds = Some(thresholds)
-    }
-}
+    };
+    if let Some(entry) = collection
+        .words
+        .iter()
+        .find(|w| w.base_weight() <= 0.0 || !w.base_weight().is_finite())
+    {
+        eprintln!(
+            "Error with {file_name}: {} needs a weight above 0",
+            entry.get_word()
+        );
+        process::exit(1);
+    }
+    if let Some(entry) = collection
+        .words
+        .iter()
+        .find(|w| w.weight() <= 0.0 || !w.weight().is_finite())
+    {
+        eprintln!(
+            "Error with {file_name}: the weight of {} is out of range with a rarity of {}",
+            entry.get_word(),
+            CONFIG.rarity
+        );
+        process::exit(1);
+    }
+    collection.calculate_thresholds();
+    collection
+}
+
+pub type Nouns = WeightedCollection<Noun>;
+pub type Pronouns = WeightedCollection<Pronoun>;
+pub type Verbs = WeightedCollection<Verb>;
+pub type Adverbs = WeightedCollection<Adverb>;
+pub type Adjectives = WeightedCollection<Adjective>;
+pub type Prepositions = WeightedCollection<Preposition>;
+pub type Determiners = WeightedCollection<Determiner>;
+pub type Conjunctions = WeightedCollection<Conjunction>;
+pub type Interjections = WeightedCollection<Interjection>;
+pub type Numerals = WeightedCollection<Numeral>;
 
-// Colletion of adverbs
-#[derive(Deserialize)]
-pub struct Adverbs {
-    pub words: Vec<Adverb>,
-    #[serde(skip)]
-    pub thresholds: Option<[(f32, AdverbType); 6]>,
-}
-impl Collection<Adverb, AdverbType> for Adverbs {
-    fn select(&self) -> Arc<dyn Word + '_> {
-        let thresholds = self.thresholds.as_ref().unwrap();
-        loop {
-            let random_f32: f32 = rand::random_range(0.00..1.00);
-            for &(threshold, ref adverb_type) in thresholds {
-                if random_f32 <= threshold {
-                    if let Some(adverb) = self.find_of_type(&adverb_type) {
-                        return Arc::new(adverb);
-                    } else {
-                        eprintln!("No adverbs of type {:?}", adverb_type);
-                    }
-                }
-            }
+lazy_static! {
+    pub static ref NOUNS: Nouns = {
+        let mut nouns = load("nouns.toml", include_str!("../words/nouns.toml"));
+        let groups = noun_groups(&nouns);
+        nouns.set_groups(groups);
+        nouns
+    };
+    pub static ref PRONOUNS: Pronouns =
+        load("pronouns.toml", include_str!("../words/pronouns.toml"));
+    pub static ref VERBS: Verbs = {
+        let mut verbs = load("verbs.toml", include_str!("../words/verbs.toml"));
+        share_irregular_forms(&mut verbs);
+        share_arguments(&mut verbs);
+        verbs
+    };
+    pub static ref ADVERBS: Adverbs = load("adverbs.toml", include_str!("../words/adverbs.toml"));
+    pub static ref ADJECTIVES: Adjectives =
+        load("adjectives.toml", include_str!("../words/adjectives.toml"));
+    pub static ref PREPOSITIONS: Prepositions = load(
+        "prepositions.toml",
+        include_str!("../words/prepositions.toml")
+    );
+    pub static ref DETERMINERS: Determiners = load(
+        "determiners.toml",
+        include_str!("../words/determiners.toml")
+    );
+    pub static ref CONJUNCTIONS: Conjunctions = load(
+        "conjunctions.toml",
+        include_str!("../words/conjunctions.toml")
+    );
+    pub static ref INTERJECTIONS: Interjections = load(
+        "interjections.toml",
+        include_str!("../words/interjections.toml")
+    );
+    pub static ref NUMERALS: Numerals =
+        load("numerals.toml", include_str!("../words/numerals.toml"));
+}
+
+/// A verb is listed once per type, the forms written on its `Irregular` entry are used for
+/// every type and verbs without one are inflected regularly.
+fn share_irregular_forms(verbs: &mut Verbs) {
+    let mut irregular: HashMap<String, VerbAttributes> = HashMap::new();
+    for verb in &verbs.words {
+        if verb.r#type != VerbType::Irregular {
+            continue;
         }
-    }
-    fn find_of_type(&self, r#type: &AdverbType) -> Option<&Adverb> {
-        loop {
-            let word = &self.words[rand::random_range(0..self.words.len())];
-            if &word.r#type == r#type {
-                return Some(word);
-            };
+        if verb.attributes.past.is_none() || verb.attributes.participle.is_none() {
+            eprintln!(
+                "Error with verbs.toml: irregular verb {} needs a past and participle",
+                verb.word
+            );
+            process::exit(1);
         }
+        irregular.insert(verb.word.clone(), verb.attributes.clone());
     }
-    fn calculate_thresholds(&mut self) {
-        let rates = CONFIG.adverb_type_rates;
-        #[rustfmt::skip]
-        let thresholds: [(f32, AdverbType); 6] = [
-            (rates.frequency, AdverbType::Frequency),
-            (rates.frequency + rates.degree, AdverbType::Degree),
-            (rates.frequency + rates.degree + rates.time, AdverbType::Time),
-            (rates.frequency + rates.degree + rates.time + rates.manner, AdverbType::Manner),
-            (rates.frequency + rates.degree + rates.time + rates.manner + rates.conjunctive, AdverbType::Conjunctive),
-            (rates.frequency + rates.degree + rates.time + rates.manner + rates.conjunctive + rates.place, AdverbType::Place)
-        ];
-        self.thresholds = Some(thresholds)
-    }
-}
-
-#[derive(Deserialize)]
-pub struct Adjectives {
-    pub words: Vec<Adjective>,
-    #[serde(skip)]
-    pub thresholds: Option<[(f32, AdjectiveType); 8]>,
-}
-impl Collection<Adjective, AdjectiveType> for Adjectives {
-    fn select(&self) -> Arc<dyn Word + '_> {
-        let thresholds = self.thresholds.as_ref().unwrap();
-        loop {
-            let random_f32: f32 = rand::random_range(0.00..1.00);
-            for &(threshold, ref adjective_type) in thresholds {
-                if random_f32 <= threshold {
-                    if let Some(adjective) = self.find_of_type(&adjective_type) {
-                        return Arc::new(adjective);
-                    } else {
-                        eprintln!("No adjectives of type {:?}", adjective_type)
-                    }
-                }
-            }
+    for verb in &mut verbs.words {
+        if verb.attributes.form.is_some() || verb.attributes.has_irregular_forms() {
+            continue;
         }
-    }
-    fn find_of_type(&self, r#type: &AdjectiveType) -> Option<&Adjective> {
-        loop {
-            let word = &self.words[rand::random_range(0..self.words.len())];
-            if &word.r#type == r#type {
-                return Some(word);
-            };
+        if let Some(attributes) = irregular.get(&verb.word) {
+            verb.attributes.third_person = attributes.third_person.clone();
+            verb.attributes.past = attributes.past.clone();
+            verb.attributes.gerund = attributes.gerund.clone();
+            verb.attributes.participle = attributes.participle.clone();
         }
     }
-    fn calculate_thresholds(&mut self) {
-        let rates = CONFIG.adjective_type_rates;
-        #[rustfmt::skip]
-        let thresholds: [(f32, AdjectiveType); 8] = [
-            (rates.numeral, AdjectiveType::Numeral),
-            (rates.numeral + rates.interrogative, AdjectiveType::Interrogative),
-            (rates.numeral + rates.interrogative + rates.distributive, AdjectiveType::Distributive),
-            (rates.numeral + rates.interrogative + rates.distributive + rates.descriptive, AdjectiveType::Descriptive),
-            (rates.numeral + rates.interrogative + rates.distributive + rates.descriptive + rates.possessive, AdjectiveType::Possessive),
-            (rates.numeral + rates.interrogative + rates.distributive + rates.descriptive + rates.possessive + rates.demonstrative, AdjectiveType::Demonstrative),
-            (rates.numeral + rates.interrogative + rates.distributive + rates.descriptive + rates.possessive + rates.demonstrative + rates.quantitative, AdjectiveType::Quantitative),
-            (rates.numeral + rates.interrogative + rates.distributive + rates.descriptive + rates.possessive + rates.demonstrative + rates.quantitative + rates.proper, AdjectiveType::Proper),
-        ];
-        self.thresholds = Some(thresholds)
-    }
 }
 
-#[derive(Deserialize)]
-pub struct Prepositions {
-    pub words: Vec<Preposition>,
-    #[serde(skip)]
-    pub thresholds: Option<[(f32, PrepositionType); 4]>,
-}
-impl Collection<Preposition, PrepositionType> for Prepositions {
-    fn select(&self) -> Arc<dyn Word + '_> {
-        let thresholds = self.thresholds.as_ref().unwrap();
-        loop {
-            let random_f32: f32 = rand::random_range(0.00..1.00);
-            for &(threshold, ref preposition_type) in thresholds {
-                if random_f32 <= threshold {
-                    if let Some(prepositon) = self.find_of_type(preposition_type) {
-                        return Arc::new(prepositon);
-                    } else {
-                        eprintln!("No prepositions of type {:?}", preposition_type)
-                    }
+/// Splits the nouns into one group for every type, tangibility and countability, weighted by
+/// the product of their rates, so nouns are picked from the joint distribution. Groups without
+/// nouns are left out, and nouns that are both countable and uncountable are in both groups.
+fn noun_groups(nouns: &Nouns) -> Vec<(f32, Vec<(f32, u32)>)> {
+    let rates = CONFIG.noun_rates;
+    let tangibilities = normalise(vec![
+        (rates.tangibility_rates.concrete, Tangibility::Concrete),
+        (rates.tangibility_rates.r#abstract, Tangibility::Abstract),
+    ]);
+    let countabilities = normalise(vec![
+        (rates.countability_rates.countable, Countability::Countable),
+        (
+            rates.countability_rates.uncountable,
+            Countability::Uncountable,
+        ),
+    ]);
+    let mut groups: Vec<(f32, Vec<(f32, u32)>)> = Vec::new();
+    for (type_rate, noun_type) in normalise(NounType::rates()) {
+        for &(tangibility_rate, tangibility) in &tangibilities {
+            for &(countability_rate, countability) in &countabilities {
+                let indices: Vec<u32> = (0..nouns.words.len() as u32)
+                    .filter(|&i| {
+                        let noun = &nouns.words[i as usize];
+                        let counts = noun.attributes.countability;
+                        noun.r#type == noun_type
+                            && noun.attributes.tangibility == tangibility
+                            && (counts == countability || counts == Countability::Both)
+                    })
+                    .collect();
+                let rate = type_rate * tangibility_rate * countability_rate;
+                if !indices.is_empty() && rate > 0.00 {
+                    groups.push((rate, nouns.weighted(&indices)));
                 }
             }
         }
     }
-    fn find_of_type(&self, r#type: &PrepositionType) -> Option<&Preposition> {
-        loop {
-            let word = &self.words[rand::random_range(0..self.words.len())];
-            if &word.r#type == r#type {
-                return Some(word);
-            };
-        }
-    }
-    fn calculate_thresholds(&mut self) {
-        let rates = CONFIG.preposition_type_rates;
-        #[rustfmt::skip]
-        let thresholds: [(f32, PrepositionType); 4] = [
-        (rates.place, PrepositionType::Place),
-        (rates.place + rates.time, PrepositionType::Time),
-        (rates.place + rates.time + rates.movement, PrepositionType::Movement),
-        (rates.place + rates.time + rates.movement + rates.purpose, PrepositionType::Purpose)
-        ];
-        self.thresholds = Some(thresholds)
+    if groups.is_empty() {
+        eprintln!("No nouns match the noun rates");
+        process::exit(1);
     }
+    groups
 }
 
-#[derive(Deserialize)]
-pub struct Determiners {
-    pub words: Vec<Determiner>,
-    #[serde(skip)]
-    pub thresholds: Option<[(f32, DeterminerType); 8]>,
-}
-impl Collection<Determiner, DeterminerType> for Determiners {
-    fn select(&self) -> Arc<dyn Word + '_> {
-        let thresholds = self.thresholds.as_ref().unwrap();
-        loop {
-            let random_f32: f32 = rand::random_range(0.00..1.00);
-            for &(threshold, ref determiner_type) in thresholds {
-                if random_f32 <= threshold {
-                    if let Some(determiner) = self.find_of_type(determiner_type) {
-                        return Arc::new(determiner);
-                    } else {
-                        eprintln!("No determiners of type {:?}", determiner_type)
-                    }
-                }
-            }
-        }
-    }
-    fn find_of_type(&self, r#type: &DeterminerType) -> Option<&Determiner> {
-        loop {
-            let word = &self.words[rand::random_range(0..self.words.len())];
-            if &word.r#type == r#type {
-                return Some(word);
-            };
+/// The argument expectations written on one entry of a verb are used for every type.
+fn share_arguments(verbs: &mut Verbs) {
+    let mut arguments: HashMap<String, (Option<Feature>, Option<Feature>)> = HashMap::new();
+    for verb in &verbs.words {
+        if verb.attributes.subject.is_some() || verb.attributes.object.is_some() {
+            arguments
+                .entry(verb.word.clone())
+                .or_insert((verb.attributes.subject, verb.attributes.object));
         }
     }
-    fn calculate_thresholds(&mut self) {
-        let rates = CONFIG.determiner_type_rates;
-        #[rustfmt::skip]
-        let thresholds: [(f32, DeterminerType); 8] = [
-        (rates.distributive, DeterminerType::Distributive),
-        (rates.distributive + rates.article, DeterminerType::Article),
-        (rates.distributive + rates.article + rates.demonstrative, DeterminerType::Demonstrative),
-        (rates.distributive + rates.article + rates.demonstrative + rates.possessive, DeterminerType::Possessive),
-        (rates.distributive + rates.article + rates.demonstrative + rates.possessive + rates.quantifier, DeterminerType::Quantifier),
-        (rates.distributive + rates.article + rates.demonstrative + rates.possessive + rates.quantifier + rates.negative, DeterminerType::Negative),
-        (rates.distributive + rates.article + rates.demonstrative + rates.possessive + rates.quantifier + rates.negative + rates.relative, DeterminerType::Relative),
-        (rates.distributive + rates.article + rates.demonstrative + rates.possessive + rates.quantifier + rates.negative + rates.relative + rates.interrogative, DeterminerType::Interrogative)
-        ];
-        self.thresholds = Some(thresholds)
-    }
-}
-#[derive(Deserialize)]
-pub struct Conjunctions {
-    pub words: Vec<Conjunction>,
-    #[serde(skip)]
-    pub thresholds: Option<[(f32, ConjunctionType); 3]>,
-}
-impl Collection<Conjunction, ConjunctionType> for Conjunctions {
-    fn select(&self) -> Arc<dyn Word + '_> {
-        let thresholds = self.thresholds.as_ref().unwrap();
-        loop {
-            let random_f32 = rand::random_range(0.00..1.00);
-            for &(threshold, ref conjunction_type) in thresholds {
-                if random_f32 <= threshold {
-                    if let Some(conjunction) = self.find_of_type(conjunction_type) {
-                        return Arc::new(conjunction);
-                    } else {
-                        eprintln!("No conjunction of type {:?}", conjunction_type)
-                    }
-                }
-            }
+    for verb in &mut verbs.words {
+        if verb.attributes.subject.is_some() || verb.attributes.object.is_some() {
+            continue;
         }
-    }
-    fn find_of_type(&self, r#type: &ConjunctionType) -> Option<&Conjunction> {
-        loop {
-            let word = &self.words[rand::random_range(0..self.words.len())];
-            if &word.r#type == r#type {
-                return Some(word);
-            };
+        if let Some(&(subject, object)) = arguments.get(&verb.word) {
+            verb.attributes.subject = subject;
+            verb.attributes.object = object;
         }
     }
-    fn calculate_thresholds(&mut self) {
-        let rates = CONFIG.conjunction_type_rates;
-        #[rustfmt::skip]
-        let thresholds: [(f32, ConjunctionType); 3] = [
-        (rates.coordinating, ConjunctionType::Coordinating),
-        (rates.coordinating + rates.subordinating, ConjunctionType::Subordinating),
-        (rates.coordinating + rates.subordinating + rates.correlative, ConjunctionType::Correlative)
-        ];
-        self.thresholds = Some(thresholds)
+}
+
+/// The word list of a part of speech.
+pub fn collection(word_type: WordType) -> &'static dyn Lexicon {
+    match word_type {
+        WordType::Noun => &*NOUNS,
+        WordType::Pronoun => &*PRONOUNS,
+        WordType::Verb => &*VERBS,
+        WordType::Adverb => &*ADVERBS,
+        WordType::Adjective => &*ADJECTIVES,
+        WordType::Preposition => &*PREPOSITIONS,
+        WordType::Determiner => &*DETERMINERS,
+        WordType::Conjunction => &*CONJUNCTIONS,
+        WordType::Interjection => &*INTERJECTIONS,
+        WordType::Numeral => &*NUMERALS,
     }
 }
 
-lazy_static! {
-    pub static ref NOUNS: Nouns = {
-        let content = include_str!("../words/nouns.toml");
-        let mut nouns: Nouns = match toml::from_str(&content) {
-            Ok(n) => n,
-            Err(e) => {
-                eprintln!("Error with nouns.toml: {e:#}");
-                process::exit(1);
-            }
-        };
-        nouns.calculate_thresholds();
-        nouns
-    };
-    pub static ref VERBS: Verbs = {
-        let content = include_str!("../words/verbs.toml");
-        let mut verbs: Verbs = match toml::from_str(&content) {
-            Ok(v) => v,
-            Err(e) => {
-                eprintln!("Error with verbs.toml: {e:#}");
-                process::exit(1);
-            }
-        };
-        verbs.calculate_thresholds();
-        verbs
-    };
-    pub static ref ADVERBS: Adverbs = {
-        let content = include_str!("../words/adverbs.toml");
-        let mut adverbs: Adverbs = match toml::from_str(&content) {
-            Ok(a) => a,
-            Err(e) => {
-                eprintln!("Error with adverbs.toml: {e:#}");
-                process::exit(1);
-            }
-        };
-        adverbs.calculate_thresholds();
-        adverbs
-    };
-    pub static ref ADJECTIVES: Adjectives = {
-        let content = include_str!("../words/adjectives.toml");
-        let mut adjectives: Adjectives = match toml::from_str(&content) {
-            Ok(a) => a,
-            Err(e) => {
-                eprintln!("Error with adjectives.toml: {e:#}");
-                process::exit(1);
-            }
-        };
-        adjectives.calculate_thresholds();
-        adjectives
-    };
-    pub static ref PREPOSITIONS: Prepositions = {
-        let content = include_str!("../words/prepositions.toml");
-        let mut prepositions: Prepositions = match toml::from_str(&content) {
-            Ok(p) => p,
-            Err(e) => {
-                eprintln!("Error with prepositions.toml: {e:#}");
-                process::exit(1);
-            }
-        };
-        prepositions.calculate_thresholds();
-        prepositions
-    };
-    pub static ref DETERMINERS: Determiners = {
-        let content = include_str!("../words/determiners.toml");
-        let mut determiners: Determiners = match toml::from_str(&content) {
-            Ok(d) => d,
-            Err(e) => {
-                eprintln!("Error with determiners.toml: {e:#}");
-                process::exit(1);
-            }
-        };
-        determiners.calculate_thresholds();
-        determiners
-    };
-    pub static ref CONJUNCTIONS: Conjunctions = {
-        let content = include_str!("../words/conjunctions.toml");
-        let mut conjunctions: Conjunctions = match toml::from_str(&content) {
-            Ok(c) => c,
-            Err(e) => {
-                eprintln!("Error with conjunctions.toml: {e:#}");
-                process::exit(1);
-            }
-        };
-        conjunctions.calculate_thresholds();
-        conjunctions
-    };
+/// Thresholds for picking one of the words by weight.
+pub fn weighted(indices: &[u32], word_type: WordType) -> Vec<(f32, u32)> {
+    thresholds(
+        indices
+            .iter()
+            .map(|&i| (collection(word_type).word(i).weight(), i))
+            .collect(),
+    )
 }